@@ -1,43 +1,171 @@
+use std::time::Duration;
+
+use crossterm::terminal;
+use minesweeper::{
+    board::{
+        board_struct::Board,
+        error::{BoardError, TextError},
+    },
+    difficulty::Difficulty,
+    replay::Replay,
+    solver::Deduction,
+    vec2::Vec2,
+};
 use termint::{
     enums::Modifier,
-    geometry::{Constraint, TextAlign, Vec2},
-    prelude::Event,
-    term::{Action, Application, Frame},
+    geometry::{Constraint, TextAlign},
+    prelude::{Event, MouseButton},
+    term::{backend::MouseEventKind, Action, Application, Frame},
     widgets::{Element, Layout, ToSpan},
 };
 
 use crate::{
-    args::Difficulty,
-    board::board_struct::Board,
-    game_state::{GameState, Screen},
+    config::Config,
+    leaderboard::{Leaderboard, Score},
     message::Message,
+    player::Player,
+    recorder::Recorder,
+    save::Save,
+    screen::Screen,
+    stats::Stats,
+    theme::Theme,
+    tui::custom::CustomForm,
 };
 
 #[derive(Debug)]
 pub struct App {
     pub board: Board,
-    pub state: GameState,
     pub screen: Screen,
+    pub config: Config,
+    pub theme: Theme,
     pub picker_state: usize,
+    pub form: CustomForm,
+    /// Seed of the next created board, random when [`None`]
+    pub seed: Option<u64>,
+    /// Whether to generate only boards solvable without guessing
+    pub no_guess: bool,
+    /// Whether the cursor is displayed - hidden when using mouse
+    pub selectable: bool,
+    /// Currently displayed hint
+    pub hint: Option<Deduction>,
+    /// Message displayed in the bottom bar of the game
+    pub msg: Option<String>,
+    /// Mine probabilities of the cells, when the overlay is shown
+    pub probs: Option<Vec<Option<f64>>>,
+    /// Saved game, which can be resumed from the difficulty picker
+    pub saved: Option<Save>,
+    /// Seconds of the game time shown in the top bar
+    pub shown_time: u64,
+    pub stats: Stats,
+    pub leaderboard: Leaderboard,
+    /// Statistics and leaderboard before the last finished game was recorded,
+    /// restored when the game becomes assisted by undoing its last move
+    pub recorded: Option<(Stats, Leaderboard)>,
+    /// Won game waiting for the player name to be added to the leaderboard
+    pub score: Option<Score>,
+    /// Player name entered for the leaderboard
+    pub name: String,
+    /// Rank of the last score added to the leaderboard
+    pub rank: Option<usize>,
+    /// Recording of the current game
+    pub recorder: Recorder,
+    /// Player of the replay, when playing back a replay
+    pub player: Option<Player>,
+    /// Position of the top left visible cell, when the board doesn't fit
+    /// into the terminal
+    pub scroll: Vec2,
+    /// Size of the terminal in characters
+    pub term_size: Vec2,
+    /// Mouse buttons currently held down, pressing left and right button
+    /// together chords the cell
+    pub held: Vec<MouseButton>,
 }
 
 impl App {
-    /// Creates new [`App`]
-    pub fn new(diff: Option<Difficulty>) -> Self {
-        let (board, screen) = match diff {
-            Some(dif) => {
-                let (w, h, m) = dif.config();
-                (Board::new(Vec2::new(w, h), m), Screen::Game)
-            }
-            None => (Board::new(Vec2::new(0, 0), 0), Screen::DiffPicker),
+    /// Creates new [`App`] with the given configuration. Fails when the
+    /// difficulty isn't valid.
+    pub fn new(
+        diff: Option<Difficulty>,
+        seed: Option<u64>,
+        no_guess: bool,
+        config: Config,
+    ) -> Result<Self, BoardError> {
+        let mut app = Self {
+            seed,
+            no_guess,
+            config,
+            ..Default::default()
         };
+        if let Some(diff) = diff {
+            app.new_board(diff)?;
+        }
+        Ok(app)
+    }
 
-        Self {
-            board,
-            state: GameState::Playing,
-            screen,
-            picker_state: 0,
+    /// Creates new board with given difficulty and opens the game screen.
+    /// Fails when the difficulty isn't valid.
+    pub fn new_board(&mut self, diff: Difficulty) -> Result<(), BoardError> {
+        let (w, h, m) = diff.config();
+        let mut board = Board::new(Vec2::new(w, h), m)?
+            .with_no_guess(self.no_guess)
+            .with_questions(self.config.questions);
+        if let Some(seed) = self.seed.take() {
+            board = board.with_seed(seed);
         }
+        self.end_game();
+        self.recorder = Recorder::new(&board);
+        self.board = board;
+        self.hint = None;
+        self.msg = None;
+        self.update_probs();
+        self.center_view(self.board.cur);
+        self.screen = Screen::Game;
+        Ok(())
+    }
+
+    /// Starts game on the given imported board and opens the game screen
+    pub fn play_board(&mut self, board: Board) {
+        self.end_game();
+        self.board = board.with_questions(self.config.questions);
+        self.recorder = Recorder::new(&self.board);
+        self.hint = None;
+        self.msg = None;
+        self.update_probs();
+        self.center_view(self.board.cur);
+        self.screen = Screen::Game;
+    }
+
+    /// Resumes the given saved game and opens the game screen
+    pub fn resume(&mut self, save: Save) {
+        self.end_game();
+        self.recorder = match save.replay {
+            Some(replay) => Recorder::resume(replay),
+            None => Recorder::new(&save.board),
+        };
+        self.board = save.board;
+        self.board.resume();
+        self.hint = None;
+        self.msg = None;
+        self.update_probs();
+        self.center_view(self.board.cur);
+        self.screen = Screen::Game;
+    }
+
+    /// Opens the replay screen playing back the given replay. Fails when
+    /// the board of the replay is invalid.
+    pub fn play_replay(&mut self, replay: Replay) -> Result<(), TextError> {
+        let player = Player::new(replay)?;
+        self.board = player.start.clone();
+        self.center_view(self.board.cur);
+        self.player = Some(player);
+        self.screen = Screen::Replay;
+        Ok(())
+    }
+
+    /// Gets the difficulty of the current board
+    pub fn board_diff(&self) -> Difficulty {
+        let size = self.board.size;
+        Difficulty::from_config(size.x, size.y, self.board.mines)
     }
 
     /// Small screen to be displayed, when game can't fit
@@ -65,21 +193,52 @@ impl Application for App {
             Screen::Game => self.render_game(),
             Screen::Help => self.render_help(),
             Screen::DiffPicker => self.render_dp(),
+            Screen::Stats => self.render_stats(),
+            Screen::NameInput => self.render_name_input(),
+            Screen::Leaderboard => self.render_leaderboard(),
+            Screen::Replay => self.render_replay(),
+            Screen::Custom => self.render_custom(),
+            Screen::Summary => self.render_summary(),
         }
     }
 
     fn event(&mut self, event: Event) -> Action {
         match event {
             Event::Key(key) => {
-                self.board.selectable = true;
+                self.selectable = true;
                 match &self.screen {
                     Screen::Game => self.listen_game(key),
                     Screen::Help => self.listen_help(key),
                     Screen::DiffPicker => self.listen_dp(key),
+                    Screen::Stats => self.listen_stats(key),
+                    Screen::NameInput => self.listen_name_input(key),
+                    Screen::Leaderboard => self.listen_leaderboard(key),
+                    Screen::Replay => self.listen_replay(key),
+                    Screen::Custom => self.listen_custom(key),
+                    Screen::Summary => self.listen_summary(key),
                 }
             }
-            Event::Mouse(_) => {
-                self.board.selectable = false;
+            Event::Mouse(mouse) => {
+                self.selectable = false;
+                match &mouse.kind {
+                    MouseEventKind::Down(button) => {
+                        self.held.push(button.clone())
+                    }
+                    MouseEventKind::Up(button) => {
+                        self.held.retain(|b| b != button)
+                    }
+                    _ => {}
+                }
+                let scrollable =
+                    matches!(self.screen, Screen::Game | Screen::Replay);
+                if scrollable && self.scroll_view(&mouse) {
+                    Action::RENDER
+                } else {
+                    Action::NONE
+                }
+            }
+            Event::Resize(w, h) => {
+                self.term_size = Vec2::new(w, h);
                 Action::NONE
             }
             _ => Action::NONE,
@@ -90,18 +249,55 @@ impl Application for App {
         match &self.screen {
             Screen::Game => self.message_game(message),
             Screen::DiffPicker => self.message_dp(message),
-            Screen::Help => Action::NONE,
+            _ => Action::NONE,
         }
     }
+
+    fn update(&mut self, delta: Duration) -> Action {
+        if self.screen == Screen::Replay {
+            return self.update_replay(delta);
+        }
+
+        let secs = self.board.time().as_secs();
+        if self.screen != Screen::Game || secs == self.shown_time {
+            return Action::NONE;
+        }
+        self.shown_time = secs;
+        Action::RENDER
+    }
 }
 
 impl Default for App {
     fn default() -> Self {
+        let board = Board::default();
         Self {
-            board: Board::new(Vec2::new(0, 0), 0),
-            state: GameState::Playing,
+            recorder: Recorder::new(&board),
+            player: None,
+            board,
             screen: Screen::DiffPicker,
+            config: Config::default(),
+            theme: Theme::default(),
             picker_state: 0,
+            form: CustomForm::default(),
+            seed: None,
+            no_guess: false,
+            selectable: true,
+            hint: None,
+            msg: None,
+            probs: None,
+            saved: None,
+            shown_time: 0,
+            stats: Stats::default(),
+            leaderboard: Leaderboard::default(),
+            recorded: None,
+            score: None,
+            name: String::new(),
+            rank: None,
+            scroll: Vec2::default(),
+            term_size: terminal::size()
+                .map(|(w, h)| Vec2::new(w as usize, h as usize))
+                .unwrap_or_default(),
+            held: vec![],
         }
     }
 }
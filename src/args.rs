@@ -1,44 +1,9 @@
-use pareg::{ArgErrCtx, ArgError, FromArg, Pareg};
-use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
-use crate::error::Result;
-
-#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
-pub enum Difficulty {
-    Easy,
-    #[default]
-    Medium,
-    Hard,
-    Custom {
-        width: usize,
-        height: usize,
-        mines: usize,
-    },
-}
-
-impl Difficulty {
-    pub fn from_index(id: usize) -> Self {
-        match id {
-            0 => Difficulty::Easy,
-            1 => Difficulty::Medium,
-            _ => Difficulty::Hard,
-        }
-    }
+use minesweeper::difficulty::Difficulty;
+use pareg::{ArgErrCtx, ArgError, Pareg};
 
-    /// Gets size and number of mines
-    pub fn config(&self) -> (usize, usize, usize) {
-        match self {
-            Difficulty::Easy => (9, 9, 10),
-            Difficulty::Medium => (16, 16, 40),
-            Difficulty::Hard => (30, 16, 99),
-            Difficulty::Custom {
-                width,
-                height,
-                mines,
-            } => (*width, *height, *mines),
-        }
-    }
-}
+use crate::error::Result;
 
 #[derive(Debug, Clone, Copy, Default)]
 pub enum Action {
@@ -46,11 +11,29 @@ pub enum Action {
     Play,
     Help,
     Config,
+    Stats,
+    Replay,
+    Solve,
+    Simulate,
 }
 
 #[derive(Debug, Default)]
 pub struct Args {
     pub diff: Option<Difficulty>,
+    pub seed: Option<u64>,
+    pub no_guess: bool,
+    /// Number of games to simulate
+    pub games: Option<usize>,
+    /// Saved game to resume
+    pub load: Option<PathBuf>,
+    /// Board in the text format to play or solve
+    pub board: Option<PathBuf>,
+    /// Whether the solution is printed as JSON
+    pub json: bool,
+    /// Replay to play back
+    pub replay: Option<PathBuf>,
+    /// Name of the theme overriding the configured theme
+    pub theme: Option<String>,
     pub action: Action,
 }
 
@@ -63,16 +46,35 @@ impl Args {
         while let Some(arg) = args.next() {
             match arg {
                 "-d" | "--diff" | "--difficulty" => {
-                    parsed.diff = Some(args.next_arg()?)
+                    parsed.diff = Some(args.next_manual(diff_arg)?)
                 }
                 "-c" | "--custom" => {
-                    parsed.diff = Some(Difficulty::Custom {
+                    let diff = Difficulty::Custom {
                         width: args.next_arg()?,
                         height: args.next_arg()?,
                         mines: args.next_arg()?,
-                    })
+                    };
+                    diff.validate()?;
+                    parsed.diff = Some(diff);
                 }
+                "-s" | "--seed" => parsed.seed = Some(args.next_arg()?),
+                "-n" | "--no-guess" => parsed.no_guess = true,
+                "-l" | "--load" => parsed.load = Some(args.next_arg()?),
+                "-b" | "--board" => parsed.board = Some(args.next_arg()?),
+                "-t" | "--theme" => parsed.theme = Some(args.next_arg()?),
                 "config" => parsed.action = Action::Config,
+                "stats" => parsed.action = Action::Stats,
+                "replay" => {
+                    parsed.action = Action::Replay;
+                    parsed.replay = Some(args.next_arg()?);
+                }
+                "solve" => {
+                    parsed.action = Action::Solve;
+                    parsed.board = Some(args.next_arg()?);
+                }
+                "--json" => parsed.json = true,
+                "simulate" => parsed.action = Action::Simulate,
+                "-g" | "--games" => parsed.games = Some(args.next_arg()?),
                 "-h" | "--help" | "help" => parsed.action = Action::Help,
                 _ => return Err(args.err_unknown_argument().into()),
             }
@@ -82,19 +84,18 @@ impl Args {
     }
 }
 
-impl<'a> FromArg<'a> for Difficulty {
-    fn from_arg(arg: &'a str) -> pareg::Result<Self> {
-        match arg {
-            "easy" => Ok(Self::Easy),
-            "medium" => Ok(Self::Medium),
-            "hard" => Ok(Self::Hard),
-            v => Err(ArgError::FailedToParse(Box::new(
-                ArgErrCtx::from_msg(
-                    format!("Invalid difficulty `{v}`").into(),
-                    v.to_string(),
-                )
-                .hint("Valid options are `easy`, `medium` or `hard`"),
-            ))),
-        }
+/// Parses [`Difficulty`] from given argument
+fn diff_arg(arg: &str) -> pareg::Result<Difficulty> {
+    match arg {
+        "easy" => Ok(Difficulty::Easy),
+        "medium" => Ok(Difficulty::Medium),
+        "hard" => Ok(Difficulty::Hard),
+        v => Err(ArgError::FailedToParse(Box::new(
+            ArgErrCtx::from_msg(
+                format!("Invalid difficulty `{v}`").into(),
+                v.to_string(),
+            )
+            .hint("Valid options are `easy`, `medium` or `hard`"),
+        ))),
     }
 }
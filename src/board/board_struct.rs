@@ -1,108 +1,224 @@
-use std::ops::{Index, IndexMut};
-
-use rand::{thread_rng, Rng};
-use termint::{
-    geometry::{Rect, Vec2},
-    prelude::MouseButton,
-    widgets::{Button, Grid},
+use std::{
+    ops::{Index, IndexMut},
+    time::{Duration, Instant},
 };
 
-use crate::{message::Message, tui::Element};
+use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{game_state::GameState, solver::Solver, vec2::Vec2};
+
+use super::{
+    cell::{Cell, CellType},
+    error::BoardError,
+    history::{Move, Snapshot},
+};
 
-use super::cell::{Cell, CellType};
+/// Maximum number of boards generated when searching for a board solvable
+/// without guessing. The number of attempts doesn't depend on time, so the
+/// same seed always generates the same board.
+pub const NO_GUESS_ATTEMPTS: usize = 1000;
+/// Maximum total number of cells of the boards generated when searching for
+/// a board solvable without guessing, limits the attempts on large boards
+pub const NO_GUESS_CELLS: usize = 1_000_000;
+/// Maximum number of cells of the board. Boards larger than the terminal
+/// are scrolled, so the limit only keeps the memory and time needed to
+/// generate, play and solve the board bounded.
+pub const MAX_CELLS: usize = 1_000_000;
 
 /// Struct representing board
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Board {
     pub cells: Vec<Cell>,
-    pub size: Vec2<usize>,
+    pub size: Vec2,
     pub mines: usize,
     generated: bool,
+    seed: u64,
+    no_guess: bool,
+    guess_free: bool,
+    /// Whether flags can be changed to question marks
+    #[serde(default)]
+    questions: bool,
     pub cur: Vec2,
-    pub selectable: bool,
+    state: GameState,
     rev: usize,
     flags: usize,
+    /// Time played before the timer was last started
+    time: Duration,
+    /// When the timer was started, [`None`] when it's not running
+    #[serde(skip)]
+    started: Option<Instant>,
+    /// Moves which can be undone, the last move is at the end
+    #[serde(default)]
+    undo: Vec<Move>,
+    /// Undone moves which can be redone, the last undone move is at the end
+    #[serde(default)]
+    redo: Vec<Move>,
+    /// Whether a move was undone after the game ended
+    #[serde(default)]
+    assisted: bool,
+    /// Cells of the board loaded from a file, restored when restarting the
+    /// game. [`None`] when the mines are generated.
+    #[serde(default)]
+    layout: Option<Vec<Cell>>,
 }
 
 impl Board {
-    /// Creates new [`Board`] with given size
-    pub fn new(size: Vec2, mines: usize) -> Self {
-        let cells = vec![Cell::new(0x00); size.x * size.y];
-        let mut board = Self {
-            size,
-            cells,
-            mines,
-            generated: false,
-            cur: center_of(size.x, size.y),
-            selectable: true,
-            rev: 0,
-            flags: 0,
+    /// Creates new [`Board`] with given size and random seed, when the size
+    /// and number of mines are valid, see [`Board::validate`]
+    pub fn new(size: Vec2, mines: usize) -> Result<Self, BoardError> {
+        Self::validate(size, mines)?;
+        Ok(Self::empty(size, mines))
+    }
+
+    /// Checks whether a playable board with given size and number of mines
+    /// can be created. The mines can't be placed on the first revealed cell
+    /// and its neighbors, so there has to be space for them elsewhere, even
+    /// when the first revealed cell is in the middle of the board.
+    pub fn validate(size: Vec2, mines: usize) -> Result<(), BoardError> {
+        if size.x == 0 || size.y == 0 {
+            return Err(BoardError::ZeroSize);
+        }
+        let cells = size.x.checked_mul(size.y);
+        let Some(cells) = cells.filter(|c| *c <= MAX_CELLS) else {
+            return Err(BoardError::TooLarge);
         };
-        if size.x > 0 && size.y > 0 {
-            board.cells[board.cur.x + board.cur.y * size.x].sel();
+        let max = cells - size.x.min(3) * size.y.min(3);
+        if mines > max {
+            return Err(BoardError::TooManyMines { max });
         }
-
-        board
+        Ok(())
     }
 
-    pub fn get_element(&self) -> Element {
-        let mut grid = Grid::new(vec![6; self.size.x], vec![3; self.size.y]);
-        for pos in Rect::new(0, 0, self.size.x, self.size.y) {
-            let button = Button::new(self[pos].element())
-                .on_click(Message::CellReveal(pos))
-                .on_press(MouseButton::Right, Message::CellFlag(pos));
-            grid.push(button, pos.x, pos.y);
+    /// Checks whether the deserialized board is consistent, so that it can
+    /// be played without panicking. Imported boards are never generated, so
+    /// their mines don't have to leave space for the first revealed cell.
+    pub fn check(&self) -> Result<(), BoardError> {
+        let mines = if self.is_imported() { 0 } else { self.mines };
+        Self::validate(self.size, mines)?;
+
+        let len = self.size.x * self.size.y;
+        let count =
+            |f: fn(&Cell) -> bool| self.cells.iter().filter(|c| f(c)).count();
+        let consistent = self.cells.len() == len
+            && self.cur.x < self.size.x
+            && self.cur.y < self.size.y
+            && self.rev == count(Cell::is_visible)
+            && self.flags == count(Cell::is_flag)
+            && self.layout.as_ref().is_none_or(|l| l.len() == len)
+            && (!self.generated || count(Cell::is_mine) == self.mines)
+            && self.undo.iter().chain(&self.redo).all(|m| m.fits(len));
+        if consistent {
+            Ok(())
+        } else {
+            Err(BoardError::Inconsistent)
         }
-        grid.into()
     }
 
-    /// Reveals current [`Cell`] and its neighbors when 0
-    pub fn reveal(&mut self) -> bool {
-        if !self.generated {
-            self.generate();
-        }
+    /// Sets the seed used to generate the mines. The same seed with the same
+    /// first revealed cell always generates the same board.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
 
-        let id = self.get_id(self.cur.x, self.cur.y);
-        if self.cells[id].is_flag() {
-            return true;
+    /// Sets whether the generated board has to be solvable without guessing.
+    /// When no such board is found in [`NO_GUESS_ATTEMPTS`], the last
+    /// generated board is used, see [`Board::is_guess_free`].
+    pub fn with_no_guess(mut self, no_guess: bool) -> Self {
+        self.no_guess = no_guess;
+        self
+    }
+
+    /// Sets whether flagging a flag changes it to question mark. Question
+    /// marks aren't counted as flags and can be revealed.
+    pub fn with_questions(mut self, questions: bool) -> Self {
+        self.questions = questions;
+        self
+    }
+
+    /// Reveals [`Cell`] on given position and its neighbors when 0. When the
+    /// cell is already visible and number of flags around it matches its
+    /// value, all its neighbors are revealed.
+    ///
+    /// Mines are generated on the first reveal, so the first revealed cell
+    /// is never a mine. Updates the [`GameState`] of the board.
+    pub fn reveal(&mut self, pos: Vec2) {
+        if self.state.is_playing() {
+            self.record(|board| board.apply_reveal(pos));
         }
-        if self.cells[id].is_mine() {
-            self.cells[id].set(0xfe);
-            return false;
+    }
+
+    /// Reveals all the neighbors of the visible [`Cell`] on given position,
+    /// when the number of flags around it matches its value (chording). Does
+    /// nothing otherwise. Updates the [`GameState`] of the board.
+    pub fn chord(&mut self, pos: Vec2) {
+        if self.state.is_playing() && self.can_chord(pos) {
+            self.record(|board| board.apply_reveal(pos));
         }
+    }
 
-        if self.cells[id].is_visible()
+    /// Checks whether the [`Cell`] on given position is visible and the
+    /// number of flags around it matches its value
+    pub fn can_chord(&self, pos: Vec2) -> bool {
+        let cell = &self[pos];
+        cell.is_visible()
             && self
-                .get_neighbors(&self.cur)
+                .get_neighbors(&pos)
                 .iter()
-                .filter(|c| self.cells[c.x + c.y * self.size.x].is_flag())
+                .filter(|n| self[**n].is_flag())
                 .count()
-                == self.cells[id].get() as usize
-        {
-            return self.reveal_vis();
-        } else {
-            self.reveal_cell(&self.cur.clone());
+                == cell.get() as usize
+    }
+
+    /// Toggles flag on [`Cell`] on given position, or cycles between flag
+    /// and question mark, see [`Board::with_questions`]. Updates the
+    /// [`GameState`] of the board.
+    pub fn flag(&mut self, pos: Vec2) {
+        if self.state.is_playing() {
+            self.record(|board| board.apply_flag(pos));
+        }
+    }
+
+    /// Undoes the last reveal or flag. Undoing a move, which ended the game,
+    /// marks the game as assisted. Returns false when there is nothing to
+    /// undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(mv) = self.undo.pop() else {
+            return false;
+        };
+        if !self.state.is_playing() {
+            self.assisted = true;
         }
 
+        mv.undo(&mut self.cells);
+        self.restore(mv.before);
+        self.redo.push(mv);
         true
     }
 
-    /// Reveals all mines
-    pub fn reveal_mines(&mut self) {
-        for cell in &mut self.cells {
-            if cell.is_mine() {
-                cell.show();
-            }
-            if !cell.is_mine() && cell.is_flag() {
-                cell.cell_type = CellType::WrongFlag;
-            }
-        }
+    /// Redoes the last undone move. Returns false when there is nothing to
+    /// redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(mv) = self.redo.pop() else {
+            return false;
+        };
+
+        mv.redo(&mut self.cells);
+        self.restore(mv.after);
+        self.undo.push(mv);
+        true
     }
 
-    /// Flags current [`Cell`]
-    pub fn flag(&mut self) {
-        let id = self.get_id(self.cur.x, self.cur.y);
-        self.flags = self.cells[id].flag(self.flags);
+    /// Checks whether a move was undone after the game ended, so the result
+    /// of the game shouldn't count
+    pub fn is_assisted(&self) -> bool {
+        self.assisted
+    }
+
+    /// Gets the [`GameState`] of the board
+    pub fn state(&self) -> GameState {
+        self.state
     }
 
     /// Returns true when game is won, else false
@@ -111,13 +227,85 @@ impl Board {
             && self.mines == self.flags
     }
 
-    /// Resets the [`Board`]
+    /// Gets the seed used to generate the mines
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Checks whether the board is generated to be solvable without guessing
+    pub fn is_no_guess(&self) -> bool {
+        self.no_guess
+    }
+
+    /// Checks whether flags can be changed to question marks
+    pub fn has_questions(&self) -> bool {
+        self.questions
+    }
+
+    /// Checks whether the mines were loaded from a file, see
+    /// [`Board::from_text`]
+    pub fn is_imported(&self) -> bool {
+        self.layout.is_some()
+    }
+
+    /// Checks whether the mines are already generated
+    pub fn is_generated(&self) -> bool {
+        self.generated
+    }
+
+    /// Checks whether the generated board was verified to be solvable
+    /// without guessing
+    pub fn is_guess_free(&self) -> bool {
+        self.guess_free
+    }
+
+    /// Gets the time played. The timer starts on the first reveal and stops
+    /// when the game ends.
+    pub fn time(&self) -> Duration {
+        self.time + self.started.map_or(Duration::ZERO, |s| s.elapsed())
+    }
+
+    /// Pauses the timer. Only the time played before pausing is serialized,
+    /// so the timer has to be paused before serializing the board.
+    pub fn pause(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.time += started.elapsed();
+        }
+    }
+
+    /// Resumes the paused timer of the game in progress
+    pub fn resume(&mut self) {
+        if self.generated && self.state.is_playing() && self.started.is_none()
+        {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    /// Resets the [`Board`] with new random seed. Imported board is
+    /// restored to the state it was loaded in instead.
     pub fn reset(&mut self) {
+        if !self.is_imported() {
+            self.seed = thread_rng().gen();
+        }
+        self.replay();
+    }
+
+    /// Resets the [`Board`] keeping the seed, so the same mines layout is
+    /// generated when starting from the same cell. Imported board is
+    /// restored to the state it was loaded in.
+    pub fn replay(&mut self) {
         self.cells = vec![Cell::new(0); self.size.x * self.size.y];
-        self.cells[self.cur.x + self.cur.y * self.size.x].sel();
         self.generated = false;
+        self.guess_free = false;
+        self.state = GameState::Playing;
         self.rev = 0;
         self.flags = 0;
+        self.time = Duration::ZERO;
+        self.started = None;
+        self.undo.clear();
+        self.redo.clear();
+        self.assisted = false;
+        self.restore_layout();
     }
 
     /// Gets flags left
@@ -127,11 +315,7 @@ impl Board {
 
     /// Selects the cell on given position. This doesn't check bounds.
     pub fn select(&mut self, pos: Vec2) {
-        self.cells[self.cur.x + self.cur.y * self.size.x].selected(false);
         self.cur = pos;
-        if self.selectable {
-            self.cells[self.cur.x + self.cur.y * self.size.x].sel();
-        }
     }
 
     /// Centers the cursor
@@ -168,32 +352,191 @@ impl Board {
 
 // Private methods implementations
 impl Board {
-    /// Generates the [`Board`] - fills it with mines
-    fn generate(&mut self) {
+    /// Creates new [`Board`] with given size and random seed without
+    /// checking whether it's valid
+    fn empty(size: Vec2, mines: usize) -> Self {
+        Self {
+            size,
+            cells: vec![Cell::new(0x00); size.x * size.y],
+            mines,
+            generated: false,
+            seed: thread_rng().gen(),
+            no_guess: false,
+            guess_free: false,
+            questions: false,
+            cur: center_of(size.x, size.y),
+            state: GameState::Playing,
+            rev: 0,
+            flags: 0,
+            time: Duration::ZERO,
+            started: None,
+            undo: vec![],
+            redo: vec![],
+            assisted: false,
+            layout: None,
+        }
+    }
+
+    /// Creates generated [`Board`] with the given cells, where the mines
+    /// are set. Computes the numbers of the cells and counts the mines,
+    /// flags and revealed cells.
+    pub(super) fn from_layout(
+        size: Vec2,
+        cells: Vec<Cell>,
+        seed: Option<u64>,
+    ) -> Self {
+        let mut board = Self::empty(size, 0);
+        board.cells = cells;
+        if let Some(seed) = seed {
+            board.seed = seed;
+        }
+
+        for id in 0..board.cells.len() {
+            if board.cells[id].is_mine() {
+                board.mines += 1;
+                board.inc_neighbors(id);
+            }
+        }
+        board.layout = Some(board.cells.clone());
+        board.restore_layout();
+        board
+    }
+
+    /// Sets the cells of the imported board to the loaded ones and counts
+    /// the flags and revealed cells. Does nothing when not imported.
+    fn restore_layout(&mut self) {
+        let Some(layout) = &self.layout else {
+            return;
+        };
+        self.cells = layout.clone();
+        self.generated = true;
+        self.rev = self.cells.iter().filter(|c| c.is_visible()).count();
+        self.flags = self.cells.iter().filter(|c| c.is_flag()).count();
+        if self.win() {
+            self.state = GameState::Win;
+        }
+    }
+
+    /// Applies the given change to the board and records it as a move,
+    /// which can be undone
+    fn record(&mut self, change: impl FnOnce(&mut Self)) {
+        let cells = self.cells.clone();
+        let before = self.snapshot();
+        change(self);
+        if let Some(mv) =
+            Move::new(&cells, &self.cells, before, self.snapshot())
+        {
+            self.undo.push(mv);
+            self.redo.clear();
+        }
+    }
+
+    /// Reveals the cell and updates the [`GameState`]
+    fn apply_reveal(&mut self, pos: Vec2) {
+        if !self.generated {
+            self.generate(pos);
+        }
+        // Imported boards are generated before the first reveal
+        self.resume();
+
+        if !self.reveal_pos(pos) {
+            self.state = GameState::GameOver;
+            self.pause();
+            self.reveal_mines();
+        } else if self.win() {
+            self.state = GameState::Win;
+            self.pause();
+        }
+    }
+
+    /// Toggles the flag and updates the [`GameState`]
+    fn apply_flag(&mut self, pos: Vec2) {
+        let id = self.get_id(pos.x, pos.y);
+        self.flags = self.cells[id].flag(self.flags, self.questions);
+        if self.win() {
+            self.state = GameState::Win;
+            self.pause();
+        }
+    }
+
+    /// Gets the state of the board besides the cells
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            generated: self.generated,
+            state: self.state,
+            rev: self.rev,
+            flags: self.flags,
+        }
+    }
+
+    /// Restores the state of the board besides the cells and pauses or
+    /// resumes the timer based on the restored state
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.generated = snapshot.generated;
+        self.state = snapshot.state;
+        self.rev = snapshot.rev;
+        self.flags = snapshot.flags;
+        if self.generated && self.state.is_playing() {
+            self.resume();
+        } else {
+            self.pause();
+        }
+    }
+
+    /// Generates the [`Board`] - fills it with mines, except around the
+    /// given position. When no guess mode is enabled, generates boards until
+    /// one solvable from the given position is found or the attempts run
+    /// out, see [`NO_GUESS_ATTEMPTS`] and [`NO_GUESS_CELLS`].
+    fn generate(&mut self, pos: Vec2) {
         self.generated = true;
-        let mut rng = thread_rng();
-
-        let mut cannot = self.get_neighbors(&self.cur);
-        cannot.push(Vec2::new(self.cur.x, self.cur.y));
-
-        for _ in 0..self.mines {
-            let mut x = rng.gen_range(0..self.size.x);
-            let mut y = rng.gen_range(0..self.size.y);
-
-            let mut id = self.get_id(x, y);
-            while self.cells[id].get() == 0xff
-                || cannot.contains(&Vec2::new(x, y))
-            {
-                x = rng.gen_range(0..self.size.x);
-                y = rng.gen_range(0..self.size.y);
-                id = self.get_id(x, y);
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        let mut cannot = self.get_neighbors(&pos);
+        cannot.push(pos);
+
+        let len = self.size.x * self.size.y;
+        let attempts = (NO_GUESS_CELLS / len).clamp(1, NO_GUESS_ATTEMPTS);
+        for attempt in 1..=attempts {
+            self.place_mines(&mut rng, &cannot);
+            if !self.no_guess {
+                return;
+            }
+            if Solver::is_solvable(self, pos) {
+                self.guess_free = true;
+                return;
+            }
+            if attempt < attempts {
+                self.cells = vec![Cell::new(0); len];
             }
+        }
+    }
 
+    /// Randomly places mines on the board, except on the given positions.
+    /// The mines are picked from the shuffled allowed cells, so it never
+    /// places more mines than there are allowed cells.
+    fn place_mines(&mut self, rng: &mut StdRng, cannot: &[Vec2]) {
+        let mut allowed: Vec<_> = (0..self.cells.len())
+            .filter(|id| !cannot.contains(&self.get_pos(*id)))
+            .collect();
+        let (mines, _) = allowed.partial_shuffle(rng, self.mines);
+        for &id in mines.iter() {
             self.cells[id].set(0xff);
             self.inc_neighbors(id);
         }
     }
 
+    /// Reveals all mines
+    fn reveal_mines(&mut self) {
+        for cell in &mut self.cells {
+            if cell.is_mine() {
+                cell.show();
+            }
+            if !cell.is_mine() && cell.is_flag() {
+                cell.cell_type = CellType::WrongFlag;
+            }
+        }
+    }
+
     /// Increments value of cell neighbors
     fn inc_neighbors(&mut self, pos: usize) {
         let x = (pos % self.size.x) as isize;
@@ -221,26 +564,48 @@ impl Board {
         }
     }
 
-    /// Reveals cell and its neighbors, when the cell value is 0
+    /// Reveals cell and its neighbors, when the cell value is 0. Uses an
+    /// explicit stack, so large openings can't overflow the call stack.
     fn reveal_cell(&mut self, coords: &Vec2) {
-        let cell = &mut self.cells[coords.x + coords.y * self.size.x];
-        if cell.is_visible() || cell.is_flag() {
-            return;
-        }
+        let mut stack = vec![*coords];
+        while let Some(pos) = stack.pop() {
+            let cell = &mut self.cells[pos.x + pos.y * self.size.x];
+            if cell.is_visible() || cell.is_flag() {
+                continue;
+            }
 
-        cell.show();
-        self.rev += 1;
-        if cell.get() == 0x00 {
-            for n in self.get_neighbors(coords) {
-                self.reveal_cell(&n);
+            cell.show();
+            self.rev += 1;
+            if cell.get() == 0x00 {
+                stack.extend(self.get_neighbors(&pos));
             }
         }
     }
 
+    /// Reveals cell on given position, returns false when mine was revealed
+    fn reveal_pos(&mut self, pos: Vec2) -> bool {
+        let id = self.get_id(pos.x, pos.y);
+        if self.cells[id].is_flag() {
+            return true;
+        }
+        if self.cells[id].is_mine() {
+            self.cells[id].set(0xfe);
+            return false;
+        }
+
+        if self.can_chord(pos) {
+            return self.reveal_vis(pos);
+        } else {
+            self.reveal_cell(&pos);
+        }
+
+        true
+    }
+
     /// Reveals neighbors of visible cell
-    fn reveal_vis(&mut self) -> bool {
+    fn reveal_vis(&mut self, pos: Vec2) -> bool {
         let mut ret = true;
-        for n in self.get_neighbors(&self.cur) {
+        for n in self.get_neighbors(&pos) {
             let cell = &mut self.cells[n.x + n.y * self.size.x];
             if cell.is_flag() {
                 continue;
@@ -260,7 +625,13 @@ impl Board {
         self.size.x * y + x
     }
 
-    fn get_neighbors(&self, coords: &Vec2) -> Vec<Vec2> {
+    /// Gets coords of the cell with given id
+    pub(super) fn get_pos(&self, id: usize) -> Vec2 {
+        Vec2::new(id % self.size.x, id / self.size.x)
+    }
+
+    /// Gets positions of all the neighbors of the given cell
+    pub fn get_neighbors(&self, coords: &Vec2) -> Vec<Vec2> {
         let mut cells = Vec::new();
         let x = coords.x as isize;
         let y = coords.y as isize;
@@ -289,6 +660,13 @@ fn center_of(x: usize, y: usize) -> Vec2 {
     Vec2::new(x.saturating_sub(1) / 2, y.saturating_sub(1) / 2)
 }
 
+impl Default for Board {
+    /// Creates empty [`Board`] without any cells
+    fn default() -> Self {
+        Self::empty(Vec2::new(0, 0), 0)
+    }
+}
+
 impl Index<usize> for Board {
     type Output = Cell;
 
@@ -316,3 +694,105 @@ impl IndexMut<Vec2> for Board {
         &mut self.cells[pos.x + pos.y * self.size.x]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_invalid_parameters() {
+        assert!(matches!(
+            Board::new(Vec2::new(3, 3), 20),
+            Err(BoardError::TooManyMines { max: 0 })
+        ));
+        assert!(matches!(
+            Board::new(Vec2::new(0, 5), 0),
+            Err(BoardError::ZeroSize)
+        ));
+        assert!(matches!(
+            Board::new(Vec2::new(MAX_CELLS, 2), 0),
+            Err(BoardError::TooLarge)
+        ));
+        assert!(matches!(
+            Board::new(Vec2::new(usize::MAX, 2), 0),
+            Err(BoardError::TooLarge)
+        ));
+        assert!(Board::new(Vec2::new(10, 1), 7).is_ok());
+        assert!(matches!(
+            Board::new(Vec2::new(10, 1), 8),
+            Err(BoardError::TooManyMines { max: 7 })
+        ));
+    }
+
+    #[test]
+    fn generate_fills_all_allowed_cells() {
+        let mut board = Board::new(Vec2::new(4, 4), 7).unwrap().with_seed(1);
+        board.reveal(Vec2::new(1, 1));
+        for id in 0..board.cells.len() {
+            let pos = board.get_pos(id);
+            assert_eq!(board[id].is_mine(), pos.x == 3 || pos.y == 3);
+        }
+    }
+
+    #[test]
+    fn undo_losing_reveal() {
+        let mut board = Board::from_text("4x2\n*...\n....\n").unwrap();
+        board.reveal(Vec2::new(3, 0));
+        let before = board.to_text();
+        board.reveal(Vec2::new(0, 0));
+        assert_eq!(board.state(), GameState::GameOver);
+        assert!(!board.is_assisted());
+
+        assert!(board.undo());
+        assert_eq!(board.state(), GameState::Playing);
+        assert!(board.is_assisted());
+        assert_eq!(board.to_text(), before);
+        assert!(!board[Vec2::new(0, 0)].is_visible());
+
+        assert!(board.redo());
+        assert_eq!(board.state(), GameState::GameOver);
+        assert!(!board.redo());
+    }
+
+    #[test]
+    fn check_rejects_inconsistent_board() {
+        let mut board = Board::from_text("4x2\n*...\n....\n").unwrap();
+        board.reveal(Vec2::new(3, 0));
+        assert_eq!(board.check(), Ok(()));
+
+        let mut broken = board.clone();
+        broken.cells.pop();
+        assert_eq!(broken.check(), Err(BoardError::Inconsistent));
+        let mut broken = board.clone();
+        broken.cur = Vec2::new(4, 0);
+        assert_eq!(broken.check(), Err(BoardError::Inconsistent));
+        let mut broken = board.clone();
+        broken.rev += 1;
+        assert_eq!(broken.check(), Err(BoardError::Inconsistent));
+        let mut broken = board;
+        broken.layout = Some(vec![]);
+        assert_eq!(broken.check(), Err(BoardError::Inconsistent));
+    }
+
+    #[test]
+    fn reveal_large_opening() {
+        let size = Vec2::new(400, 400);
+        let mut board = Board::new(size, 10).unwrap().with_seed(3);
+        board.reveal(Vec2::new(0, 0));
+        assert!(board.rev > size.x * size.y / 2);
+        assert!(board.state().is_playing());
+    }
+
+    #[test]
+    fn no_guess_generation_is_reproducible() {
+        let generate = || {
+            let mut board = Board::new(Vec2::new(16, 16), 60)
+                .unwrap()
+                .with_seed(9)
+                .with_no_guess(true);
+            board.reveal(Vec2::new(8, 8));
+            board.to_text()
+        };
+        assert_eq!(generate(), generate());
+    }
+}
@@ -1,31 +1,21 @@
-use termint::{
-    buffer::Buffer,
-    enums::{Color, Modifier, Wrap},
-    geometry::{Rect, Vec2},
-    style::Style,
-    widgets::{LayoutNode, Span, Widget},
-};
-
-use crate::{
-    message::Message,
-    tui::{widgets::button::Button, Element},
-};
+use serde::{Deserialize, Serialize};
 
 /// Enum representing cell type
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum CellType {
     Hidden,
     Visible,
     Flag,
     WrongFlag,
+    /// Hidden cell marked with question mark
+    Question,
 }
 
 /// Struct representing cell in board
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct Cell {
     pub value: u8,
     pub cell_type: CellType,
-    pub sel: bool,
 }
 
 impl Cell {
@@ -34,7 +24,6 @@ impl Cell {
         Self {
             value,
             cell_type: CellType::Hidden,
-            sel: false,
         }
     }
 
@@ -60,26 +49,28 @@ impl Cell {
         }
     }
 
-    /// Toggles [`Cell`] as flag (if possible)
-    pub fn flag(&mut self, flags: usize) -> usize {
-        if self.cell_type == CellType::Flag {
-            self.cell_type = CellType::Hidden;
-            return flags - 1;
-        } else if self.cell_type == CellType::Hidden {
-            self.cell_type = CellType::Flag;
-            return flags + 1;
+    /// Toggles [`Cell`] as flag (if possible). When `questions` is true,
+    /// flag is changed to question mark, which is then removed. Returns the
+    /// updated number of flags.
+    pub fn flag(&mut self, flags: usize, questions: bool) -> usize {
+        match self.cell_type {
+            CellType::Hidden => {
+                self.cell_type = CellType::Flag;
+                flags + 1
+            }
+            CellType::Flag => {
+                self.cell_type = match questions {
+                    true => CellType::Question,
+                    false => CellType::Hidden,
+                };
+                flags - 1
+            }
+            CellType::Question => {
+                self.cell_type = CellType::Hidden;
+                flags
+            }
+            _ => flags,
         }
-        flags
-    }
-
-    /// Toggles whether [`Cell`] is selected
-    pub fn sel(&mut self) {
-        self.sel = !self.sel;
-    }
-
-    /// Sets whether [`Cell`] is selected based on the given `bool`.
-    pub fn selected(&mut self, sel: bool) {
-        self.sel = sel;
     }
 
     /// Checks whether cell is mine
@@ -87,6 +78,11 @@ impl Cell {
         self.value == 0xff || self.value == 0xfe
     }
 
+    /// Checks whether cell is the mine, which exploded
+    pub fn is_exploded(&self) -> bool {
+        self.value == 0xfe
+    }
+
     /// Checks whether cell is revealed
     pub fn is_visible(&self) -> bool {
         self.cell_type == CellType::Visible
@@ -97,98 +93,8 @@ impl Cell {
         self.cell_type == CellType::Flag
     }
 
-    /// Gets the corresponding cell element
-    pub fn element(&self) -> Element {
-        match self.cell_type {
-            CellType::Visible => self.clone().into(),
-            _ => self.get_hidden().into(),
-        }
-    }
-}
-
-impl Widget<Message> for Cell {
-    fn render(&self, buffer: &mut Buffer, node: &LayoutNode) {
-        self.render_visible(buffer, node.area);
-    }
-
-    fn height(&self, _size: &Vec2) -> usize {
-        3
-    }
-
-    fn width(&self, _size: &Vec2) -> usize {
-        7
-    }
-
-    fn children(&self) -> Vec<&Element> {
-        vec![]
-    }
-}
-
-impl Cell {
-    fn render_visible(&self, buffer: &mut Buffer, rect: Rect) {
-        let lb = Color::Hex(0x797979);
-        let db = match self.sel {
-            true if self.value == 0xfe => Color::Hex(0xd20000),
-            true => Color::Hex(0xa0a0a0),
-            false if self.value == 0xfe => Color::Hex(0xee0000),
-            false => Color::Hex(0xbcbcbc),
-        };
-
-        let mut pos = *rect.pos();
-        buffer.set_str_styled(" ▆▆▆▆▆", &pos, Style::new().bg(lb).fg(db));
-
-        pos.y += 1;
-        let (val, fg) = self.get_value();
-        buffer.set_str_styled(
-            format!("   {val} "),
-            &pos,
-            Style::new().bg(db).fg(fg),
-        );
-        buffer.set_bg(lb, &pos);
-
-        pos.y += 1;
-        buffer.set_str_styled(" ▂▂▂▂▂", &pos, Style::new().bg(db).fg(lb));
-        buffer.set_bg(lb, &pos);
-    }
-
-    fn get_hidden(&self) -> Button<Message> {
-        let text = match self.cell_type {
-            CellType::Flag => {
-                Span::new(" ▶ ").fg(Color::Hex(0xff0000)).wrap(Wrap::Letter)
-            }
-            CellType::WrongFlag => Span::new(" ▶ ")
-                .modifier(Modifier::STRIKED)
-                .fg(Color::Hex(0xff0000))
-                .wrap(Wrap::Letter),
-            _ => Span::new("   ").wrap(Wrap::Letter),
-        };
-        Button::new(text).selected(self.sel)
-    }
-
-    fn get_value(&self) -> (&str, Color) {
-        match self.value {
-            0x01 => ("1 ", Color::Hex(0x0000ff)),
-            0x02 => ("2 ", Color::Hex(0x007700)),
-            0x03 => ("3 ", Color::Hex(0xff0000)),
-            0x04 => ("4 ", Color::Hex(0x000077)),
-            0x05 => ("5 ", Color::Hex(0x770000)),
-            0x06 => ("6 ", Color::Hex(0x007777)),
-            0x07 => ("7 ", Color::Hex(0x000000)),
-            0x08 => ("8 ", Color::Hex(0x777777)),
-            0xfe | 0xff => ("💣", Color::Default),
-            _ => ("  ", Color::Default),
-        }
-    }
-}
-
-impl From<Cell> for Element {
-    fn from(value: Cell) -> Self {
-        Element::new(value)
-    }
-}
-
-impl From<Cell> for Box<dyn Widget<Message>> {
-    fn from(value: Cell) -> Self {
-        Box::new(value)
+    /// Checks whether cell is marked with question mark
+    pub fn is_question(&self) -> bool {
+        self.cell_type == CellType::Question
     }
 }
@@ -0,0 +1,63 @@
+use thiserror::Error;
+
+use super::board_struct::MAX_CELLS;
+
+/// Reason why a board with given size and number of mines can't be created
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum BoardError {
+    #[error("Board can't be empty")]
+    ZeroSize,
+    #[error("Board can have at most {MAX_CELLS} cells")]
+    TooLarge,
+    /// There has to be space for the mines outside of the first revealed
+    /// cell and its neighbors
+    #[error("At most {max} mines fit")]
+    TooManyMines { max: usize },
+    /// Loaded board doesn't match its size or its own counts, see
+    /// [`Board::check`](super::board_struct::Board::check)
+    #[error("Board data is inconsistent")]
+    Inconsistent,
+}
+
+/// Reason why a board can't be read from the text format, see
+/// [`Board::from_text`](super::board_struct::Board::from_text)
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TextError {
+    #[error("Missing board size")]
+    MissingSize,
+    #[error(
+        "Line {line}: invalid board size `{size}`, expected `WIDTHxHEIGHT`"
+    )]
+    InvalidSize { line: usize, size: String },
+    #[error("Line {line}: invalid seed `{seed}`")]
+    InvalidSeed { line: usize, seed: String },
+    #[error("Line {line}: invalid number of mines `{mines}`")]
+    InvalidMines { line: usize, mines: String },
+    /// Number of mines has to be given, when some cells are unknown
+    #[error("Missing number of mines")]
+    MissingMines,
+    #[error(transparent)]
+    Board(#[from] BoardError),
+    #[error("Line {line}: expected {width} cells, found {found}")]
+    RowLength {
+        line: usize,
+        width: usize,
+        found: usize,
+    },
+    #[error("Expected {height} rows, found {found}")]
+    RowCount { height: usize, found: usize },
+    #[error("Line {line}: unknown cell `{cell}`")]
+    UnknownCell { line: usize, cell: char },
+    /// Board with `?` cells can be solved, but not played
+    #[error("Line {line}: board with `?` cells can't be played")]
+    UnknownContent { line: usize },
+    #[error("Line {line}: expected {expected} mines, found {found}")]
+    MineCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// Revealed number doesn't match the mines around it
+    #[error("Line {line}: number of mines around cell `{cell}` is {mines}")]
+    WrongNumber { line: usize, cell: char, mines: u8 },
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_state::GameState;
+
+use super::cell::Cell;
+
+/// Change of the board made by a single reveal, chord or flag, which can be
+/// undone and redone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Move {
+    /// Ids of the changed cells with the cell before and after the move
+    cells: Vec<(usize, Cell, Cell)>,
+    pub before: Snapshot,
+    pub after: Snapshot,
+}
+
+/// State of the board besides the cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub generated: bool,
+    pub state: GameState,
+    pub rev: usize,
+    pub flags: usize,
+}
+
+impl Move {
+    /// Creates new [`Move`] from the board before and after the move.
+    /// Returns [`None`] when the move didn't change anything.
+    pub fn new(
+        cells: &[Cell],
+        new_cells: &[Cell],
+        before: Snapshot,
+        after: Snapshot,
+    ) -> Option<Self> {
+        let cells: Vec<_> = cells
+            .iter()
+            .zip(new_cells)
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(id, (old, new))| (id, old.clone(), new.clone()))
+            .collect();
+
+        if cells.is_empty() && before == after {
+            return None;
+        }
+        Some(Self {
+            cells,
+            before,
+            after,
+        })
+    }
+
+    /// Checks whether the move can be applied to a board with `len` cells
+    pub fn fits(&self, len: usize) -> bool {
+        self.cells.iter().all(|(id, _, _)| *id < len)
+            && [self.before, self.after]
+                .iter()
+                .all(|s| s.rev <= len && s.flags <= len)
+    }
+
+    /// Sets the changed cells to their state before the move
+    pub fn undo(&self, cells: &mut [Cell]) {
+        for (id, old, _) in &self.cells {
+            cells[*id] = old.clone();
+        }
+    }
+
+    /// Sets the changed cells to their state after the move
+    pub fn redo(&self, cells: &mut [Cell]) {
+        for (id, _, new) in &self.cells {
+            cells[*id] = new.clone();
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::board_struct::Board;
+
+impl Board {
+    /// Gets the 3BV of the board - minimum number of clicks needed to reveal
+    /// all the cells without flagging. Each opening counts as one click and
+    /// each number not bordering any opening also counts as one.
+    pub fn bbbv(&self) -> usize {
+        let (openings, lone) = self.regions();
+        openings.len() + lone.iter().filter(|l| **l).count()
+    }
+
+    /// Gets the 3BV of the revealed part of the board - the revealed
+    /// openings and the revealed numbers not bordering any opening
+    pub fn bbbv_solved(&self) -> usize {
+        let (openings, lone) = self.regions();
+        let visible = |id: &usize| self.cells[*id].is_visible();
+        openings.iter().filter(|o| o.iter().any(visible)).count()
+            + (0..lone.len())
+                .filter(|id| lone[*id] && visible(id))
+                .count()
+    }
+
+    /// Gets the number of openings - connected areas of empty cells, which
+    /// are revealed by a single click
+    pub fn openings(&self) -> usize {
+        self.regions().0.len()
+    }
+
+    /// Gets the number of islands - connected groups of numbers not
+    /// bordering any opening
+    pub fn islands(&self) -> usize {
+        let (_, mut lone) = self.regions();
+        let mut res = 0;
+        for id in 0..lone.len() {
+            if !lone[id] {
+                continue;
+            }
+
+            res += 1;
+            lone[id] = false;
+            let mut stack = vec![self.get_pos(id)];
+            while let Some(pos) = stack.pop() {
+                for n in self.get_neighbors(&pos) {
+                    let nid = self.get_id(n.x, n.y);
+                    if lone[nid] {
+                        lone[nid] = false;
+                        stack.push(n);
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// Gets the ratio of the revealed cells to all the cells without mine
+    pub fn cleared(&self) -> f64 {
+        let safe = self.cells.iter().filter(|c| !c.is_mine());
+        let (revealed, total) = safe.fold((0, 0), |(rev, total), c| {
+            (rev + c.is_visible() as usize, total + 1)
+        });
+        match total {
+            0 => 1.,
+            total => revealed as f64 / total as f64,
+        }
+    }
+}
+
+// Private methods implementations
+impl Board {
+    /// Gets ids of the empty cells of each opening and whether each cell is
+    /// a number not bordering any opening
+    fn regions(&self) -> (Vec<Vec<usize>>, Vec<bool>) {
+        let mut seen = vec![false; self.cells.len()];
+        let mut openings = vec![];
+        for id in 0..self.cells.len() {
+            if seen[id] || self.cells[id].get() != 0 {
+                continue;
+            }
+
+            let mut opening = vec![id];
+            seen[id] = true;
+            let mut stack = vec![self.get_pos(id)];
+            while let Some(pos) = stack.pop() {
+                for n in self.get_neighbors(&pos) {
+                    let nid = self.get_id(n.x, n.y);
+                    if seen[nid] {
+                        continue;
+                    }
+                    seen[nid] = true;
+                    if self.cells[nid].get() == 0 {
+                        opening.push(nid);
+                        stack.push(n);
+                    }
+                }
+            }
+            openings.push(opening);
+        }
+
+        let lone = self
+            .cells
+            .iter()
+            .zip(seen)
+            .map(|(c, s)| !s && !c.is_mine())
+            .collect();
+        (openings, lone)
+    }
+}
@@ -1,2 +1,6 @@
 pub mod board_struct;
 pub mod cell;
+pub mod error;
+mod history;
+mod metrics;
+pub mod text;
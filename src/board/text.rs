@@ -0,0 +1,386 @@
+//! Plain-text format of the board, which can be written by hand, put into
+//! bug reports and diffed:
+//!
+//! ```text
+//! # Lines starting with `#` are comments
+//! 5x4
+//! seed 42
+//! mines 2
+//! 01*..
+//! 02...
+//! 01F..
+//! .....
+//! ```
+//!
+//! The first line is the size of the board as `WIDTHxHEIGHT`, followed by
+//! an optional seed and number of mines and a row of cells for each line of
+//! the board:
+//!
+//! - `.` hidden cell
+//! - `*` hidden mine
+//! - `F` flagged mine
+//! - `f` flagged cell without mine
+//! - `0` to `8` revealed cell with its number of neighboring mines
+//! - `?` hidden cell, which may or may not be a mine
+//!
+//! Boards with `?` cells can't be played, only solved, see
+//! [`Solver::from_text`]. The number of mines has to be given for them.
+
+use crate::{solver::Solver, vec2::Vec2};
+
+use super::{
+    board_struct::Board,
+    cell::{Cell, CellType},
+    error::TextError,
+};
+
+/// Board read from the text format, before its numbers are checked
+struct Layout {
+    size: Vec2,
+    seed: Option<u64>,
+    /// Number of mines given by the text and its line
+    mines: Option<(usize, usize)>,
+    cells: Vec<Cell>,
+    /// Id, line and character of each revealed number
+    numbers: Vec<(usize, usize, char)>,
+    /// Line of the first cell with unknown content
+    unknown: Option<usize>,
+}
+
+impl Board {
+    /// Reads the [`Board`] from the text format. The mines are given by the
+    /// text, so the board is already generated. Numbers of the revealed
+    /// cells have to match the mines around them, as well as the number of
+    /// mines, when it's given.
+    pub fn from_text(text: &str) -> Result<Self, TextError> {
+        let layout = Layout::parse(text)?;
+        if let Some(line) = layout.unknown {
+            return Err(TextError::UnknownContent { line });
+        }
+
+        let board = Board::from_layout(layout.size, layout.cells, layout.seed);
+        if let Some((line, expected)) = layout.mines {
+            if expected != board.mines {
+                return Err(TextError::MineCount {
+                    line,
+                    expected,
+                    found: board.mines,
+                });
+            }
+        }
+        for (id, line, cell) in layout.numbers {
+            let mines = board[id].get();
+            if cell.to_digit(10) != Some(mines as u32) {
+                return Err(TextError::WrongNumber { line, cell, mines });
+            }
+        }
+        Ok(board)
+    }
+
+    /// Writes the [`Board`] in the text format. Question marks are written
+    /// as hidden cells and mines as hidden or flagged mines, even when they
+    /// are revealed at the end of the game.
+    pub fn to_text(&self) -> String {
+        let (size, seed, mines) = (self.size, self.seed(), self.mines);
+        let mut res =
+            format!("{}x{}\nseed {seed}\nmines {mines}\n", size.x, size.y);
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                res.push(cell_char(&self[Vec2::new(x, y)]));
+            }
+            res.push('\n');
+        }
+        res
+    }
+}
+
+impl Solver {
+    /// Reads the position to be solved from the text format. Numbers of the
+    /// revealed cells are used as they are, so the mines don't have to be
+    /// known, but then the number of mines has to be given. When all the
+    /// cells are known, the board is checked as in [`Board::from_text`].
+    pub fn from_text(text: &str) -> Result<Self, TextError> {
+        let layout = Layout::parse(text)?;
+        if layout.unknown.is_none() {
+            return Board::from_text(text).map(|board| Solver::new(&board));
+        }
+        let Some((_, mines)) = layout.mines else {
+            return Err(TextError::MissingMines);
+        };
+
+        let mut numbers = vec![None; layout.cells.len()];
+        for (id, _, cell) in layout.numbers {
+            numbers[id] = cell.to_digit(10).map(|n| n as u8);
+        }
+        let flags = layout.cells.iter().map(|c| c.is_flag()).collect();
+        let solver = Solver::from_parts(layout.size, mines, numbers, flags);
+        Ok(solver)
+    }
+}
+
+impl Layout {
+    /// Parses the text format without checking the numbers
+    fn parse(text: &str) -> Result<Self, TextError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .peekable();
+
+        let (line, size) = lines.next().ok_or(TextError::MissingSize)?;
+        let size = parse_size(size).ok_or_else(|| TextError::InvalidSize {
+            line,
+            size: size.to_string(),
+        })?;
+        Board::validate(size, 0)?;
+
+        let mut seed = None;
+        let mut mines = None;
+        while let Some(&(line, header)) = lines.peek() {
+            if let Some(val) = header.strip_prefix("seed") {
+                let val = val.trim();
+                seed =
+                    Some(val.parse().map_err(|_| TextError::InvalidSeed {
+                        line,
+                        seed: val.to_string(),
+                    })?);
+            } else if let Some(val) = header.strip_prefix("mines") {
+                let val = val.trim();
+                let count =
+                    val.parse().map_err(|_| TextError::InvalidMines {
+                        line,
+                        mines: val.to_string(),
+                    })?;
+                mines = Some((line, count));
+            } else {
+                break;
+            }
+            lines.next();
+        }
+
+        let mut cells = Vec::with_capacity(size.x * size.y);
+        let mut numbers = vec![];
+        let mut unknown = None;
+        let mut rows = 0;
+        for (line, row) in lines {
+            rows += 1;
+            if rows > size.y {
+                continue;
+            }
+            let found = row.chars().count();
+            if found != size.x {
+                return Err(TextError::RowLength {
+                    line,
+                    width: size.x,
+                    found,
+                });
+            }
+            for c in row.chars() {
+                if c.is_ascii_digit() {
+                    numbers.push((cells.len(), line, c));
+                } else if c == '?' {
+                    unknown = unknown.or(Some(line));
+                }
+                cells.push(
+                    parse_cell(c)
+                        .ok_or(TextError::UnknownCell { line, cell: c })?,
+                );
+            }
+        }
+        if rows != size.y {
+            return Err(TextError::RowCount {
+                height: size.y,
+                found: rows,
+            });
+        }
+
+        Ok(Self {
+            size,
+            seed,
+            mines,
+            cells,
+            numbers,
+            unknown,
+        })
+    }
+}
+
+/// Parses the board size written as `WIDTHxHEIGHT`
+fn parse_size(size: &str) -> Option<Vec2> {
+    let (w, h) = size.split_once('x')?;
+    Some(Vec2::new(w.trim().parse().ok()?, h.trim().parse().ok()?))
+}
+
+/// Parses the cell, the numbers are computed from the mines later
+fn parse_cell(c: char) -> Option<Cell> {
+    let (value, cell_type) = match c {
+        '.' | '?' => (0x00, CellType::Hidden),
+        '*' => (0xff, CellType::Hidden),
+        'F' => (0xff, CellType::Flag),
+        'f' => (0x00, CellType::Flag),
+        '0'..='8' => (0x00, CellType::Visible),
+        _ => return None,
+    };
+    Some(Cell { value, cell_type })
+}
+
+/// Gets the character of the cell in the text format
+fn cell_char(cell: &Cell) -> char {
+    match cell.cell_type {
+        CellType::Flag if cell.is_mine() => 'F',
+        CellType::Flag | CellType::WrongFlag => 'f',
+        _ if cell.is_mine() => '*',
+        CellType::Visible => (b'0' + cell.get()) as char,
+        CellType::Hidden | CellType::Question => '.',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{board::error::BoardError, game_state::GameState};
+
+    use super::*;
+
+    const BOARD: &str = "\
+        # Comment\n\
+        5x4\n\
+        seed 42\n\
+        mines 2\n\
+        01*..\n\
+        02...\n\
+        01F..\n\
+        .f...\n";
+
+    #[test]
+    fn reads_board() {
+        let board = Board::from_text(BOARD).unwrap();
+        assert_eq!(board.size, Vec2::new(5, 4));
+        assert_eq!(board.seed(), 42);
+        assert_eq!(board.mines, 2);
+        assert_eq!(board.flags_left(), 0);
+        assert!(board.is_imported() && board.is_generated());
+        assert!(board[Vec2::new(2, 0)].is_mine());
+        assert!(board[Vec2::new(2, 2)].is_flag());
+        assert!(board[Vec2::new(1, 3)].is_flag());
+        assert!(!board[Vec2::new(1, 3)].is_mine());
+        assert_eq!(board[Vec2::new(1, 1)].get(), 2);
+        assert!(board[Vec2::new(1, 1)].is_visible());
+    }
+
+    #[test]
+    fn round_trips() {
+        let text = Board::from_text(BOARD).unwrap().to_text();
+        assert_eq!(
+            text,
+            "5x4\nseed 42\nmines 2\n01*..\n02...\n01F..\n.f...\n"
+        );
+        assert_eq!(Board::from_text(&text).unwrap().to_text(), text);
+    }
+
+    #[test]
+    fn reports_errors() {
+        let err = |text: &str| Board::from_text(text).unwrap_err();
+        assert_eq!(err("# only comment\n"), TextError::MissingSize);
+        assert_eq!(
+            err("5by4\n"),
+            TextError::InvalidSize {
+                line: 1,
+                size: "5by4".into()
+            }
+        );
+        assert_eq!(err("0x4\n"), TextError::Board(BoardError::ZeroSize));
+        assert_eq!(
+            err("100000x100000\n"),
+            TextError::Board(BoardError::TooLarge)
+        );
+        assert_eq!(
+            err("2x1\nseed x\n..\n"),
+            TextError::InvalidSeed {
+                line: 2,
+                seed: "x".into()
+            }
+        );
+        assert_eq!(
+            err("2x2\n..\n...\n"),
+            TextError::RowLength {
+                line: 3,
+                width: 2,
+                found: 3
+            }
+        );
+        assert_eq!(
+            err("2x2\n..\n..\n..\n"),
+            TextError::RowCount {
+                height: 2,
+                found: 3
+            }
+        );
+        assert_eq!(
+            err("2x1\n.x\n"),
+            TextError::UnknownCell { line: 2, cell: 'x' }
+        );
+        assert_eq!(
+            err("2x1\nmines x\n..\n"),
+            TextError::InvalidMines {
+                line: 2,
+                mines: "x".into()
+            }
+        );
+        assert_eq!(
+            err("2x1\nmines 2\n*.\n"),
+            TextError::MineCount {
+                line: 2,
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            err("2x2\nmines 1\n..\n.?\n"),
+            TextError::UnknownContent { line: 4 }
+        );
+        assert_eq!(
+            err("2x1\n*0\n"),
+            TextError::WrongNumber {
+                line: 2,
+                cell: '0',
+                mines: 1
+            }
+        );
+    }
+
+    #[test]
+    fn restart_restores_board() {
+        let text = "3x3\nseed 7\nmines 8\n***\n*.*\n***\n";
+        let mut board = Board::from_text(text).unwrap();
+        board.reveal(Vec2::new(0, 0));
+        assert_eq!(board.state(), GameState::GameOver);
+
+        board.reset();
+        assert_eq!(board.state(), GameState::Playing);
+        assert_eq!(board.to_text(), text);
+        board.reveal(Vec2::new(1, 1));
+        assert_eq!(board.state(), GameState::Playing);
+        assert_eq!(board[Vec2::new(1, 1)].get(), 8);
+    }
+
+    #[test]
+    fn reads_unknown_position() {
+        let text = "5x4\nmines 3\n0001?\n0012?\n111??\n?11??\n";
+        let solver = Solver::from_text(text).unwrap();
+        assert_eq!(solver.size(), Vec2::new(5, 4));
+        assert_eq!(solver.mines_left(), 3);
+
+        let err = Solver::from_text("2x1\n0?\n").unwrap_err();
+        assert_eq!(err, TextError::MissingMines);
+        let err = Solver::from_text("2x1\n*0\n").unwrap_err();
+        assert_eq!(
+            err,
+            TextError::WrongNumber {
+                line: 2,
+                cell: '0',
+                mines: 1
+            }
+        );
+    }
+}
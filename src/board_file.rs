@@ -0,0 +1,32 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use minesweeper::{board::board_struct::Board, solver::Solver};
+
+use crate::{config::boards_dir, error::Result};
+
+/// Loads board in the text format from the given file
+pub fn load_board(path: impl AsRef<Path>) -> Result<Board> {
+    Ok(Board::from_text(&read_to_string(path)?)?)
+}
+
+/// Loads board position to be solved in the text format from the given
+/// file, see [`Solver::from_text`]
+pub fn load_position(path: impl AsRef<Path>) -> Result<Solver> {
+    Ok(Solver::from_text(&read_to_string(path)?)?)
+}
+
+/// Exports the board in the text format to a new file in the boards
+/// directory. Returns path of the file.
+pub fn export_board(board: &Board) -> Result<PathBuf> {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    create_dir_all(boards_dir())?;
+    let file = boards_dir().join(format!("{secs}-{}.txt", board.seed()));
+    write(&file, board.to_text())?;
+    Ok(file)
+}
@@ -1,22 +1,71 @@
 use std::{
-    fs::File,
-    io::{BufReader, BufWriter},
+    fs::{create_dir_all, File},
+    io::{self, BufReader, BufWriter},
     path::{Path, PathBuf},
 };
 
+use minesweeper::difficulty::Difficulty;
 use serde::{Deserialize, Serialize};
 
-use crate::{args::Difficulty, error::Result};
+use crate::{
+    error::{Error, Result},
+    keys::Bindings,
+    tui::{color_mode::ColorMode, density::Density},
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Config {
     #[serde(default)]
     pub default_difficulty: Option<Difficulty>,
+    /// Whether to generate only boards solvable without guessing
+    #[serde(default)]
+    pub no_guess: bool,
+    /// Whether numbers are chorded only by the chord action and not by
+    /// revealing them
+    #[serde(default)]
+    pub explicit_chord: bool,
+    /// Whether flagging a flag changes it to question mark
+    #[serde(default)]
+    pub questions: bool,
+    /// Last custom difficulty started from the difficulty picker
+    #[serde(default)]
+    pub custom: Option<Difficulty>,
+    /// How densely the board cells are rendered
+    #[serde(default)]
+    pub density: Density,
+    /// Name of the theme, the default theme is used when [`None`]
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Whether to draw only with ASCII characters, detected from the
+    /// terminal when [`None`]
+    #[serde(default)]
+    pub ascii: Option<bool>,
+    /// Colors supported by the terminal, detected when [`None`]
+    #[serde(default)]
+    pub colors: Option<ColorMode>,
+    /// Keys bound to the actions, missing actions use the default keys
+    #[serde(default)]
+    pub keys: Bindings,
 }
 
 impl Config {
-    pub fn from_default_json() -> Self {
-        Self::from_json(config_file()).unwrap_or_default()
+    /// Loads the config from the default config file, the default config
+    /// is used when there is no config file. Fails when the config file is
+    /// invalid, so it isn't overwritten by the default config later.
+    pub fn from_default_json() -> Result<Self> {
+        let path = config_file();
+        match Self::from_json(&path) {
+            Err(Error::IOErr(e)) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(format!(
+                "Invalid config file `{}`: {e}\n\
+                Fix it by running `minesweeper config`",
+                path.display()
+            )
+            .into()),
+            res => res,
+        }
     }
 
     pub fn from_json(path: impl AsRef<Path>) -> Result<Self> {
@@ -25,6 +74,7 @@ impl Config {
     }
 
     pub fn to_default_json(&self) -> Result<()> {
+        create_dir_all(config_dir())?;
         self.to_json(config_file())
     }
 
@@ -44,3 +94,27 @@ pub fn config_dir() -> PathBuf {
 pub fn config_file() -> PathBuf {
     config_dir().join("config.json")
 }
+
+pub fn stats_file() -> PathBuf {
+    config_dir().join("stats.json")
+}
+
+pub fn leaderboard_file() -> PathBuf {
+    config_dir().join("leaderboard.json")
+}
+
+pub fn replays_dir() -> PathBuf {
+    config_dir().join("replays")
+}
+
+pub fn boards_dir() -> PathBuf {
+    config_dir().join("boards")
+}
+
+pub fn themes_dir() -> PathBuf {
+    config_dir().join("themes")
+}
+
+pub fn save_file() -> PathBuf {
+    config_dir().join("save.json")
+}
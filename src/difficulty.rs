@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    board::{board_struct::Board, error::BoardError},
+    vec2::Vec2,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+    Custom {
+        width: usize,
+        height: usize,
+        mines: usize,
+    },
+}
+
+impl Difficulty {
+    pub fn from_index(id: usize) -> Self {
+        match id {
+            0 => Difficulty::Easy,
+            1 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+
+    /// Gets the difficulty with given size and number of mines, which is
+    /// one of the predefined difficulties when it matches
+    pub fn from_config(width: usize, height: usize, mines: usize) -> Self {
+        [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard]
+            .into_iter()
+            .find(|d| d.config() == (width, height, mines))
+            .unwrap_or(Difficulty::Custom {
+                width,
+                height,
+                mines,
+            })
+    }
+
+    /// Gets size and number of mines
+    pub fn config(&self) -> (usize, usize, usize) {
+        match self {
+            Difficulty::Easy => (9, 9, 10),
+            Difficulty::Medium => (16, 16, 40),
+            Difficulty::Hard => (30, 16, 99),
+            Difficulty::Custom {
+                width,
+                height,
+                mines,
+            } => (*width, *height, *mines),
+        }
+    }
+
+    /// Checks whether a board with the difficulty can be created, see
+    /// [`Board::validate`]
+    pub fn validate(&self) -> Result<(), BoardError> {
+        let (w, h, m) = self.config();
+        Board::validate(Vec2::new(w, h), m)
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "Easy"),
+            Difficulty::Medium => write!(f, "Medium"),
+            Difficulty::Hard => write!(f, "Hard"),
+            Difficulty::Custom {
+                width,
+                height,
+                mines,
+            } => write!(f, "Custom {width}x{height}/{mines}"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use minesweeper::board::error::{BoardError, TextError};
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -7,6 +8,10 @@ pub enum Error {
     #[error(transparent)]
     IOErr(#[from] std::io::Error),
     #[error(transparent)]
+    Board(#[from] BoardError),
+    #[error(transparent)]
+    BoardText(#[from] TextError),
+    #[error(transparent)]
     Json(#[from] serde_json::Error),
     #[error(transparent)]
     Pareg(#[from] pareg::ArgError),
@@ -1,6 +1,11 @@
+use serde::{Deserialize, Serialize};
+
 /// Represents state the game is in
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(
+    Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize,
+)]
 pub enum GameState {
+    #[default]
     Playing,
     GameOver,
     Win,
@@ -11,12 +16,3 @@ impl GameState {
         *self == Self::Playing
     }
 }
-
-/// Represents which game screen is currently displayed
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
-pub enum Screen {
-    Game,
-    #[default]
-    DiffPicker,
-    Help,
-}
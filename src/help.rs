@@ -1,23 +1,64 @@
 use termint::{
     enums::Color,
-    help,
     widgets::{Grad, ToSpan},
 };
 
+use crate::tui::color_mode::ColorMode;
+
+/// Prints the help sections the same way as the termint `help!` macro, but
+/// with the colors converted to the given [`ColorMode`]
+macro_rules! help {
+    ($mode:ident; $header:literal: $($rest:tt)*) => {
+        println!("{}:", paint($header, Color::Green, $mode));
+        help!($mode; $($rest)*);
+    };
+    (
+        $mode:ident;
+        $cmd:literal $([$param:literal])* => $description:literal
+        $($rest:tt)*
+    ) => {
+        print!("  {}", paint($cmd, Color::Yellow, $mode));
+        $(print!(" [{}]", $param);)*
+        println!();
+        println!("    {}", $description);
+        help!($mode; $($rest)*);
+    };
+    ($mode:ident;) => {};
+}
+
 /// Displays help
 pub fn print_help() {
+    let mode = ColorMode::detect();
+    let author = match mode {
+        ColorMode::TrueColor => {
+            Grad::new("Martan03", (0, 220, 255), (175, 80, 255)).to_string()
+        }
+        mode => paint("Martan03", Color::Rgb(0, 220, 255), mode),
+    };
     println!(
-        "Welcome to help for {} by {}\n",
-        "minesweeper".fg(Color::Green),
-        Grad::new("Martan03", (0, 220, 255), (175, 80, 255))
+        "Welcome to help for {} by {author}\n",
+        paint("minesweeper", Color::Green, mode),
     );
     help!(
+        mode;
         "Usage":
         "minesweeper" =>
             "Starts game with the default dificulity or opens TUI difficulty \
             picker.\n"
         "minesweeper config" =>
             "Opens the configuration file in the default editor.\n"
+        "minesweeper stats" =>
+            "Prints statistics of the finished games.\n"
+        "minesweeper replay" ["file"] =>
+            "Plays back the game recorded in the given replay file.\n"
+        "minesweeper solve" ["file"] =>
+            "Prints which hidden cells of the board in the given text file \
+            are safe or mines and their mine probabilities. Prints JSON with \
+            `--json`.\n"
+        "minesweeper simulate" ["flags"] =>
+            "Plays games of the given difficulty with the built-in strategy \
+            and prints the win rate, average guesses, average 3BV and games \
+            per second. Uses `-d`, `-c`, `-s`, `-n` and `-g` flags.\n"
         "minesweeper help" => "Prints this help.\n"
         "minesweeper" ["flags"] => "Start the game with given options.\n"
         "Flags":
@@ -25,6 +66,28 @@ pub fn print_help() {
             "Sets the game difficulty.\n"
         "-c --custom" ["width"] ["height"] ["mines"] =>
             "Creates custom game with given size and amount of mines.\n"
+        "-s --seed" ["u64"] =>
+            "Sets the seed used to generate the first board.\n"
+        "-n --no-guess" =>
+            "Generates only boards solvable without guessing.\n"
+        "-l --load" ["file"] =>
+            "Resumes the game saved in the given file.\n"
+        "-b --board" ["file"] =>
+            "Plays the board written in the given text file.\n"
+        "-g --games" ["count"] =>
+            "Sets the number of simulated games, 1000 by default.\n"
+        "-t --theme" ["name"] =>
+            "Sets the color theme, `default`, `dark`, `high-contrast` or \
+            name of a theme file in the themes directory.\n"
         "-h --help" => "Displays this help."
     );
 }
+
+/// Colors the text with the color converted to the given [`ColorMode`],
+/// plain text when the colors are off
+fn paint(text: &str, color: Color, mode: ColorMode) -> String {
+    match mode {
+        ColorMode::Mono => text.into(),
+        mode => text.fg(mode.convert(color)).to_string(),
+    }
+}
@@ -0,0 +1,393 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use termint::prelude::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Action, which can be bound to keys
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum KeyAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    PanUp,
+    PanDown,
+    PanLeft,
+    PanRight,
+    Reveal,
+    /// Reveals the neighbors of a number with matching number of flags
+    Chord,
+    Flag,
+    Undo,
+    Redo,
+    Restart,
+    Replay,
+    Hint,
+    Probs,
+    Center,
+    Density,
+    /// Writes the board to a file in the text format
+    Export,
+    Help,
+    Stats,
+    Leaderboard,
+    /// Shows the summary of the finished game
+    Summary,
+    Picker,
+    /// Confirms the selected difficulty in the difficulty picker
+    Confirm,
+    Quit,
+}
+
+/// Actions of the game screen
+pub const GAME_ACTIONS: [KeyAction; 26] = [
+    KeyAction::Up,
+    KeyAction::Down,
+    KeyAction::Left,
+    KeyAction::Right,
+    KeyAction::PanUp,
+    KeyAction::PanDown,
+    KeyAction::PanLeft,
+    KeyAction::PanRight,
+    KeyAction::Reveal,
+    KeyAction::Chord,
+    KeyAction::Flag,
+    KeyAction::Undo,
+    KeyAction::Redo,
+    KeyAction::Restart,
+    KeyAction::Replay,
+    KeyAction::Hint,
+    KeyAction::Probs,
+    KeyAction::Center,
+    KeyAction::Density,
+    KeyAction::Export,
+    KeyAction::Help,
+    KeyAction::Stats,
+    KeyAction::Leaderboard,
+    KeyAction::Summary,
+    KeyAction::Picker,
+    KeyAction::Quit,
+];
+
+/// Actions of the difficulty picker
+pub const PICKER_ACTIONS: [KeyAction; 4] = [
+    KeyAction::Up,
+    KeyAction::Down,
+    KeyAction::Confirm,
+    KeyAction::Quit,
+];
+
+/// Key with modifiers, written as for example `k`, `Enter` or `Ctrl+r`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Key {
+    code: KeyCode,
+    ctrl: bool,
+    alt: bool,
+    /// Shift of characters is part of the character itself
+    shift: bool,
+}
+
+/// Keys bound to the actions
+#[derive(Debug, Clone, Serialize)]
+pub struct Bindings(BTreeMap<KeyAction, Vec<Key>>);
+
+impl Key {
+    /// Creates new [`Key`] without modifiers
+    pub fn new(code: KeyCode) -> Self {
+        Self {
+            code,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    /// Checks whether the key event is press of this key
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        let mods = &event.modifiers;
+        let shift = match event.code {
+            KeyCode::Char(_) => false,
+            _ => mods.contains(KeyModifiers::SHIFT),
+        };
+        self.code == event.code
+            && self.ctrl == mods.contains(KeyModifiers::CONTROL)
+            && self.alt == mods.contains(KeyModifiers::ALT)
+            && self.shift == shift
+    }
+
+    /// Gets the modifiers prefix, such as `Ctrl+`
+    fn modifiers(&self) -> String {
+        let mut res = String::new();
+        if self.ctrl {
+            res.push_str("Ctrl+");
+        }
+        if self.alt {
+            res.push_str("Alt+");
+        }
+        if self.shift {
+            res.push_str("Shift+");
+        }
+        res
+    }
+
+    /// Gets the name of the key without the modifiers, arrows are shown as
+    /// symbols unless `ascii` is set
+    fn code_name(&self, ascii: bool) -> String {
+        match &self.code {
+            KeyCode::Char(' ') => "Space".into(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{n}"),
+            KeyCode::Up if !ascii => "↑".into(),
+            KeyCode::Down if !ascii => "↓".into(),
+            KeyCode::Left if !ascii => "←".into(),
+            KeyCode::Right if !ascii => "→".into(),
+            code => format!("{code:?}"),
+        }
+    }
+}
+
+impl Bindings {
+    /// Gets the first of the given actions, which is bound to the pressed key
+    pub fn get(
+        &self,
+        event: &KeyEvent,
+        actions: &[KeyAction],
+    ) -> Option<KeyAction> {
+        actions.iter().copied().find(|action| {
+            self.keys(*action).iter().any(|key| key.matches(event))
+        })
+    }
+
+    /// Gets the keys bound to the action
+    pub fn keys(&self, action: KeyAction) -> &[Key] {
+        self.0.get(&action).map_or(&[], |keys| keys)
+    }
+
+    /// Gets a key bound to two different of the given actions, only the
+    /// first of them would ever be triggered by the key
+    pub fn conflict(
+        &self,
+        actions: &[KeyAction],
+    ) -> Option<(&Key, KeyAction, KeyAction)> {
+        actions.iter().enumerate().find_map(|(i, &a)| {
+            actions[i + 1..].iter().find_map(|&b| {
+                let key =
+                    self.keys(a).iter().find(|k| self.keys(b).contains(k));
+                key.map(|key| (key, a, b))
+            })
+        })
+    }
+
+    /// Gets the label of the keys bound to the given actions, such as
+    /// `←↓↑→/hjkl`. The n-th keys of the actions are grouped together.
+    /// Arrows are written by their names when `ascii` is set.
+    pub fn label(&self, actions: &[KeyAction], ascii: bool) -> String {
+        let keys: Vec<_> = actions.iter().map(|a| self.keys(*a)).collect();
+        let len = keys.iter().map(|k| k.len()).max().unwrap_or_default();
+        (0..len)
+            .map(|i| {
+                let group: Vec<_> =
+                    keys.iter().filter_map(|k| k.get(i)).collect();
+                let mods = group[0].modifiers();
+                let names: Vec<_> =
+                    group.iter().map(|k| k.code_name(ascii)).collect();
+                if group.iter().any(|k| k.modifiers() != mods) {
+                    let keys: Vec<_> =
+                        group.iter().map(|k| k.to_string()).collect();
+                    keys.join(",")
+                } else if names.iter().all(|n| n.chars().count() == 1) {
+                    format!("{mods}{}", names.concat())
+                } else {
+                    format!("{mods}{}", names.join(","))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        use KeyAction::*;
+
+        let char = |c| Key::new(KeyCode::Char(c));
+        let shift = |code| Key {
+            shift: true,
+            ..Key::new(code)
+        };
+        Self(BTreeMap::from([
+            (Up, vec![Key::new(KeyCode::Up), char('k')]),
+            (Down, vec![Key::new(KeyCode::Down), char('j')]),
+            (Left, vec![Key::new(KeyCode::Left), char('h')]),
+            (Right, vec![Key::new(KeyCode::Right), char('l')]),
+            (PanUp, vec![shift(KeyCode::Up)]),
+            (PanDown, vec![shift(KeyCode::Down)]),
+            (PanLeft, vec![shift(KeyCode::Left)]),
+            (PanRight, vec![shift(KeyCode::Right)]),
+            (Reveal, vec![char('d'), Key::new(KeyCode::Enter)]),
+            (Chord, vec![char(' ')]),
+            (Flag, vec![char('f')]),
+            (Undo, vec![char('u')]),
+            (
+                Redo,
+                vec![Key {
+                    ctrl: true,
+                    ..char('r')
+                }],
+            ),
+            (Restart, vec![char('r')]),
+            (Replay, vec![char('R')]),
+            (Hint, vec![char('H')]),
+            (Probs, vec![char('p')]),
+            (Center, vec![char('c')]),
+            (Density, vec![char('v')]),
+            (Export, vec![char('e')]),
+            (Help, vec![char('i')]),
+            (Stats, vec![char('s')]),
+            (Leaderboard, vec![char('b')]),
+            (Summary, vec![char('g')]),
+            (Picker, vec![Key::new(KeyCode::Tab)]),
+            (Confirm, vec![Key::new(KeyCode::Enter)]),
+            (Quit, vec![char('q'), Key::new(KeyCode::Esc)]),
+        ]))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bindings {
+    /// Deserializes the bindings, actions missing in the map keep their
+    /// default keys. Fails when a key is bound to two actions of the same
+    /// screen.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut bindings = Bindings::default();
+        bindings.0.extend(BTreeMap::deserialize(deserializer)?);
+        for actions in [&GAME_ACTIONS[..], &PICKER_ACTIONS] {
+            if let Some((key, a, b)) = bindings.conflict(actions) {
+                return Err(D::Error::custom(format!(
+                    "key `{key}` is bound to both `{a:?}` and `{b:?}`"
+                )));
+            }
+        }
+        Ok(bindings)
+    }
+}
+
+impl FromStr for Key {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mods, name) = match s.rsplit_once('+') {
+            // `+` itself is a valid key
+            Some((mods, "")) => (mods.strip_suffix('+').unwrap_or(""), "+"),
+            Some((mods, name)) => (mods, name),
+            None => ("", s),
+        };
+
+        let code = match name.to_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "enter" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "esc" => KeyCode::Esc,
+            "backspace" => KeyCode::Backspace,
+            "delete" => KeyCode::Delete,
+            "insert" => KeyCode::Insert,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            f if f.len() > 1 && f.starts_with('f') => {
+                KeyCode::F(f[1..].parse().map_err(|_| unknown_key(s))?)
+            }
+            _ => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return Err(unknown_key(s)),
+                }
+            }
+        };
+
+        let mut key = Key::new(code);
+        for m in mods.split('+').filter(|m| !m.is_empty()) {
+            match m.to_lowercase().as_str() {
+                "ctrl" => key.ctrl = true,
+                "alt" => key.alt = true,
+                "shift" => key.shift = true,
+                _ => return Err(format!("Unknown modifier `{m}` in `{s}`")),
+            }
+        }
+        // Shifted characters are received as the uppercase character
+        if let (true, KeyCode::Char(c)) = (key.shift, &key.code) {
+            key.code = KeyCode::Char(c.to_ascii_uppercase());
+            key.shift = false;
+        }
+        Ok(key)
+    }
+}
+
+impl TryFrom<String> for Key {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Key> for String {
+    fn from(value: Key) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match &self.code {
+            KeyCode::Up => "Up".into(),
+            KeyCode::Down => "Down".into(),
+            KeyCode::Left => "Left".into(),
+            KeyCode::Right => "Right".into(),
+            _ => self.code_name(false),
+        };
+        write!(f, "{}{name}", self.modifiers())
+    }
+}
+
+/// Gets the error message of an unknown key
+fn unknown_key(key: &str) -> String {
+    format!("Unknown key `{key}`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_bindings_have_no_conflicts() {
+        let bindings = Bindings::default();
+        assert_eq!(bindings.conflict(&GAME_ACTIONS), None);
+        assert_eq!(bindings.conflict(&PICKER_ACTIONS), None);
+    }
+
+    #[test]
+    fn rejects_conflicting_keys() {
+        let json = r#"{ "Flag": ["f", "Space"] }"#;
+        let err = serde_json::from_str::<Bindings>(json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("key `Space` is bound to both `Chord` and `Flag`"));
+
+        let json = r#"{ "Flag": ["f", "x"], "Confirm": ["f"] }"#;
+        let bindings: Bindings = serde_json::from_str(json).unwrap();
+        assert_eq!(bindings.keys(KeyAction::Flag).len(), 2);
+    }
+}
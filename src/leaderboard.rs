@@ -0,0 +1,175 @@
+use std::{
+    fs::{create_dir_all, File},
+    io::{self, BufReader, BufWriter},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use minesweeper::difficulty::Difficulty;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{config_dir, leaderboard_file},
+    error::{Error, Result},
+    stats::format_time,
+};
+
+/// Maximum number of scores kept for each difficulty
+pub const MAX_SCORES: usize = 10;
+
+/// Best scores for each difficulty
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Leaderboard {
+    #[serde(default)]
+    pub diffs: Vec<DiffScores>,
+}
+
+/// Best scores for one difficulty, sorted from the fastest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffScores {
+    pub diff: Difficulty,
+    pub scores: Vec<Score>,
+}
+
+/// Score of a won game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Score {
+    pub name: String,
+    pub time: Duration,
+    /// Seconds since unix epoch, when the game was won
+    pub date: u64,
+    pub seed: u64,
+    pub bbbv: usize,
+}
+
+impl Leaderboard {
+    /// Loads the leaderboard from the default leaderboard file, empty
+    /// leaderboard is used when there is no leaderboard file. Fails when the
+    /// leaderboard file is invalid, so it isn't overwritten later.
+    pub fn from_default_json() -> Result<Self> {
+        let path = leaderboard_file();
+        match Self::from_json(&path) {
+            Err(Error::IOErr(e)) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(format!(
+                "Invalid leaderboard file `{}`: {e}",
+                path.display()
+            )
+            .into()),
+            res => res,
+        }
+    }
+
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Self> {
+        let f = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(f)?)
+    }
+
+    pub fn to_default_json(&self) -> Result<()> {
+        create_dir_all(config_dir())?;
+        self.to_json(leaderboard_file())
+    }
+
+    pub fn to_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        let f = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(f, self)?;
+        Ok(())
+    }
+
+    /// Gets the best scores for given difficulty
+    pub fn get(&self, diff: &Difficulty) -> &[Score] {
+        self.diffs
+            .iter()
+            .find(|d| d.diff == *diff)
+            .map_or(&[], |d| &d.scores)
+    }
+
+    /// Checks whether game with given time gets to the leaderboard
+    pub fn qualifies(&self, diff: &Difficulty, time: Duration) -> bool {
+        let scores = self.get(diff);
+        scores.len() < MAX_SCORES || scores.iter().any(|s| time < s.time)
+    }
+
+    /// Adds the score to the leaderboard of given difficulty. Returns the
+    /// rank of the score, [`None`] when it's not good enough.
+    pub fn add(&mut self, diff: Difficulty, score: Score) -> Option<usize> {
+        if !self.diffs.iter().any(|d| d.diff == diff) {
+            self.diffs.push(DiffScores {
+                diff: diff.clone(),
+                scores: vec![],
+            });
+            self.diffs.sort_by_key(|d| d.diff.config());
+        }
+
+        let scores =
+            &mut self.diffs.iter_mut().find(|d| d.diff == diff)?.scores;
+        let rank = scores.iter().take_while(|s| s.time <= score.time).count();
+        if rank >= MAX_SCORES {
+            return None;
+        }
+        scores.insert(rank, score);
+        scores.truncate(MAX_SCORES);
+        Some(rank)
+    }
+}
+
+impl Score {
+    /// Creates new [`Score`] dated now
+    pub fn new(name: String, time: Duration, seed: u64, bbbv: usize) -> Self {
+        let date = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self {
+            name,
+            time,
+            date,
+            seed,
+            bbbv,
+        }
+    }
+
+    /// Gets the 3BV solved per second
+    pub fn bbbv_rate(&self) -> f64 {
+        let secs = self.time.as_secs_f64();
+        if secs == 0. {
+            return 0.;
+        }
+        self.bbbv as f64 / secs
+    }
+
+    /// Gets the score as columns of the leaderboard table
+    pub fn columns(&self, rank: usize) -> [String; 6] {
+        [
+            format!("{}.", rank + 1),
+            self.name.clone(),
+            format_time(self.time),
+            format!("{:.2}", self.bbbv_rate()),
+            format_date(self.date),
+            self.seed.to_string(),
+        ]
+    }
+}
+
+/// Headers of the leaderboard table columns
+pub const SCORES_HEADER: [&str; 6] =
+    ["#", "Name", "Time", "3BV/s", "Date", "Seed"];
+
+/// Widths of the leaderboard table columns
+pub const SCORES_WIDTHS: [usize; 6] = [4, 18, 7, 8, 12, 20];
+
+/// Formats seconds since unix epoch as `YYYY-MM-DD` date in UTC
+fn format_date(secs: u64) -> String {
+    // Converts days since epoch to civil date, see
+    // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = secs / 86400 + 719468;
+    let era = days / 146097;
+    let doe = days % 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as u64;
+    format!("{year}-{month:02}-{day:02}")
+}
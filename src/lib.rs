@@ -0,0 +1,15 @@
+//! Minesweeper game engine
+//!
+//! Contains the game rules only - board generation, revealing, flagging,
+//! chording, win/loss detection, replays and simulated games - without any
+//! rendering, so it can be used by bots, tests or other frontends. The
+//! terminal frontend is the `minesweeper` binary, which is built with the
+//! `tui` feature.
+
+pub mod board;
+pub mod difficulty;
+pub mod game_state;
+pub mod replay;
+pub mod simulation;
+pub mod solver;
+pub mod vec2;
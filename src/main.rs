@@ -4,32 +4,55 @@ use std::{
     fs::create_dir_all,
     io::{stdout, Write},
     process::{Command, ExitCode},
+    time::Instant,
 };
 use termint::{enums::Color, term::Term, widgets::ToSpan};
 
 use args::Action;
+use board_file::{load_board, load_position};
 use config::{config_dir, config_file, Config};
 use error::Result;
 use help::print_help;
+use leaderboard::Leaderboard;
+use minesweeper::{
+    board::board_struct::Board,
+    simulation::{SimulatedGame, Simulation},
+    vec2::Vec2,
+};
 use pareg::Pareg;
+use recorder::load_replay;
+use save::Save;
+use solve::Solution;
+use stats::{Stats, STATS_HEADER, STATS_WIDTHS};
+use theme::Theme;
+use tui::{color_mode::ColorMode, symbols::Symbols};
 
 use crate::args::Args;
 
 mod app;
 mod args;
-mod board;
+mod board_file;
 mod config;
 mod error;
-mod game_state;
 mod help;
+mod keys;
+mod leaderboard;
 mod message;
+mod player;
+mod recorder;
+mod save;
+mod screen;
+mod solve;
+mod stats;
+mod theme;
 mod tui;
 
 fn main() -> ExitCode {
     match run() {
         Ok(_) => ExitCode::SUCCESS,
         Err(e) => {
-            eprintln!("{} {}", "Error:".fg(Color::Red), e);
+            let red = ColorMode::detect().convert(Color::Red);
+            eprintln!("{} {}", "Error:".fg(red), e);
             ExitCode::FAILURE
         }
     }
@@ -44,17 +67,71 @@ fn run() -> Result<()> {
             Ok(())
         }
         Action::Config => config(),
+        Action::Stats => stats(),
+        Action::Replay => replay(args),
+        Action::Solve => solve(args),
+        Action::Simulate => simulate(args),
     }
 }
 
 fn play(args: Args) -> Result<()> {
-    start_game(args, Config::from_default_json())?;
+    start_game(args, Config::from_default_json()?)?;
     _ = stdout().flush();
     Ok(())
 }
 
 fn start_game(args: Args, conf: Config) -> Result<()> {
-    let mut app = App::new(args.diff.or(conf.default_difficulty));
+    let diff = args.diff.or(conf.default_difficulty.clone());
+    if let Some(diff) = &diff {
+        diff.validate()?;
+    }
+    let theme =
+        load_theme(args.theme.as_ref().or(conf.theme.as_ref()), &conf)?;
+    let no_guess = args.no_guess || conf.no_guess;
+    let mut app = App::new(diff, args.seed, no_guess, conf)?;
+    app.theme = theme;
+    app.leaderboard = Leaderboard::from_default_json()?;
+    app.stats = Stats::from_default_json()?;
+    // Default save can be removed only when it was offered, otherwise it's
+    // an unrelated unfinished game
+    let mut offered = false;
+    if let Some(path) = args.board {
+        app.play_board(load_board(path)?);
+    } else if let Some(path) = args.load {
+        app.resume(Save::from_json(path)?);
+    } else if let Some(save) = Save::from_default_json() {
+        app.offer_resume(save);
+        offered = true;
+    }
+
+    Term::default()
+        .setup()?
+        .with_mouse()
+        .small_screen(App::small_screen())
+        .run(&mut app)?;
+
+    if app.board.is_generated() && app.board.state().is_playing() {
+        Save::new(app.board, Some(app.recorder.replay)).to_default_json()
+    } else if offered && app.saved.is_none() {
+        Save::remove_default()
+    } else {
+        Ok(())
+    }
+}
+
+fn replay(args: Args) -> Result<()> {
+    let Some(path) = args.replay else {
+        return Err("Missing replay file".into());
+    };
+
+    let conf = Config::from_default_json()?;
+    let theme = args.theme.as_ref().or(conf.theme.as_ref());
+    let mut app = App {
+        theme: load_theme(theme, &conf)?,
+        config: conf,
+        ..Default::default()
+    };
+    app.play_replay(load_replay(path)?)?;
     Term::default()
         .setup()?
         .with_mouse()
@@ -63,6 +140,59 @@ fn start_game(args: Args, conf: Config) -> Result<()> {
     Ok(())
 }
 
+/// Loads the theme with given name, the default theme when [`None`]. Its
+/// symbols and colors are set by the ASCII and color modes.
+fn load_theme(name: Option<&String>, conf: &Config) -> Result<Theme> {
+    let mut theme = match name {
+        Some(name) => Theme::load(name)?,
+        None => Theme::default(),
+    };
+    theme.symbols = Symbols::new(conf.ascii);
+    Ok(theme.with_colors(conf.colors.unwrap_or_else(ColorMode::detect)))
+}
+
+fn solve(args: Args) -> Result<()> {
+    let Some(path) = args.board else {
+        return Err("Missing board file".into());
+    };
+
+    let solution = Solution::new(&load_position(path)?);
+    if args.json {
+        solution.print_json()
+    } else {
+        solution.print();
+        Ok(())
+    }
+}
+
+fn simulate(args: Args) -> Result<()> {
+    let Some(diff) = args.diff else {
+        return Err("Missing difficulty".into());
+    };
+    diff.validate()?;
+
+    let (w, h, m) = diff.config();
+    let games = args.games.unwrap_or(1000);
+    let mut sim = Simulation::default();
+    let start = Instant::now();
+    for i in 0..games {
+        let mut board =
+            Board::new(Vec2::new(w, h), m)?.with_no_guess(args.no_guess);
+        if let Some(seed) = args.seed {
+            board = board.with_seed(seed.wrapping_add(i as u64));
+        }
+        sim.push(SimulatedGame::play(&mut board));
+    }
+    let secs = start.elapsed().as_secs_f64();
+
+    println!("Simulated {games} {diff} games in {secs:.2} s\n");
+    println!("Win rate:        {:.2} %", sim.win_rate() * 100.);
+    println!("Average guesses: {:.2}", sim.avg_guesses());
+    println!("Average 3BV:     {:.2}", sim.avg_bbbv());
+    println!("Games per sec:   {:.1}", games as f64 / secs);
+    Ok(())
+}
+
 fn config() -> Result<()> {
     let editor = env::var("EDITOR").unwrap_or("vi".to_string());
     create_dir_all(config_dir())?;
@@ -74,3 +204,27 @@ fn config() -> Result<()> {
     Command::new(editor).arg(file).spawn()?.wait()?;
     Ok(())
 }
+
+fn stats() -> Result<()> {
+    let stats = Stats::from_default_json()?;
+    if stats.diffs.is_empty() {
+        println!("No finished games yet");
+        return Ok(());
+    }
+
+    print_row(&STATS_HEADER);
+    for diff in &stats.diffs {
+        print_row(&diff.columns());
+    }
+    Ok(())
+}
+
+/// Prints row of the statistics table
+fn print_row<T: AsRef<str>>(cols: &[T]) {
+    let row: String = cols
+        .iter()
+        .zip(STATS_WIDTHS)
+        .map(|(col, width)| format!("{:<width$}", col.as_ref()))
+        .collect();
+    println!("{}", row.trim_end());
+}
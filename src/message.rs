@@ -1,8 +1,9 @@
-use termint::prelude::Vec2;
+use minesweeper::vec2::Vec2;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Message {
     DiffSel(usize),
     CellReveal(Vec2),
     CellFlag(Vec2),
+    CellChord(Vec2),
 }
@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use minesweeper::{
+    board::{board_struct::Board, error::TextError},
+    replay::Replay,
+};
+
+/// Minimum playback speed
+const MIN_SPEED: f64 = 0.25;
+/// Maximum playback speed
+const MAX_SPEED: f64 = 16.;
+
+/// Plays back the replay on a board
+#[derive(Debug)]
+pub struct Player {
+    pub replay: Replay,
+    /// Board before any event is applied
+    pub start: Board,
+    /// Number of events already applied to the board
+    pub pos: usize,
+    /// Time of the playback since the start of the replay
+    pub time: Duration,
+    pub playing: bool,
+    pub speed: f64,
+}
+
+impl Player {
+    /// Creates new paused [`Player`] at the start of the replay. Fails when
+    /// the board of the replay is invalid.
+    pub fn new(replay: Replay) -> Result<Self, TextError> {
+        Ok(Self {
+            start: replay.board()?,
+            replay,
+            pos: 0,
+            time: Duration::ZERO,
+            playing: false,
+            speed: 1.,
+        })
+    }
+
+    /// Advances the playback by given time when playing and applies the
+    /// events up to the new time. Returns true when any event was applied.
+    pub fn advance(&mut self, board: &mut Board, delta: Duration) -> bool {
+        if !self.playing {
+            return false;
+        }
+
+        self.time += delta.mul_f64(self.speed);
+        let start = self.pos;
+        while self
+            .replay
+            .events
+            .get(self.pos)
+            .is_some_and(|e| e.time() <= self.time)
+        {
+            self.replay.events[self.pos].action.apply(board);
+            self.pos += 1;
+        }
+
+        if self.is_end() {
+            self.playing = false;
+            self.time = self.replay.duration();
+        }
+        self.pos != start
+    }
+
+    /// Applies the next event
+    pub fn step(&mut self, board: &mut Board) {
+        if let Some(event) = self.replay.events.get(self.pos) {
+            event.action.apply(board);
+            self.time = event.time();
+            self.pos += 1;
+        }
+    }
+
+    /// Goes back before the last applied event. The board is recreated and
+    /// all the events before are applied again.
+    pub fn step_back(&mut self, board: &mut Board) {
+        self.seek(board, self.pos.saturating_sub(1));
+    }
+
+    /// Toggles the playback, starts from the beginning when at the end
+    pub fn toggle(&mut self, board: &mut Board) {
+        if self.is_end() {
+            self.seek(board, 0);
+        }
+        self.playing = !self.playing;
+    }
+
+    /// Doubles the playback speed
+    pub fn faster(&mut self) {
+        self.speed = (self.speed * 2.).min(MAX_SPEED);
+    }
+
+    /// Halves the playback speed
+    pub fn slower(&mut self) {
+        self.speed = (self.speed / 2.).max(MIN_SPEED);
+    }
+
+    /// Checks whether all the events are applied
+    pub fn is_end(&self) -> bool {
+        self.pos >= self.replay.events.len()
+    }
+
+    /// Recreates the board with given number of events applied
+    fn seek(&mut self, board: &mut Board, pos: usize) {
+        *board = self.start.clone();
+        self.pos = 0;
+        self.time = Duration::ZERO;
+        while self.pos < pos {
+            self.step(board);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    fs::{create_dir_all, File},
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use minesweeper::{
+    board::board_struct::Board,
+    replay::{Replay, ReplayAction},
+};
+
+use crate::{config::replays_dir, error::Result};
+
+/// Records the current game and writes it to a replay file
+#[derive(Debug)]
+pub struct Recorder {
+    pub replay: Replay,
+    /// When the recording started
+    start: Instant,
+    /// File the replay is written to
+    file: PathBuf,
+}
+
+impl Recorder {
+    /// Creates new [`Recorder`] of the game on the given board
+    pub fn new(board: &Board) -> Self {
+        Self::resume(Replay::new(board))
+    }
+
+    /// Continues recording of the given replay
+    pub fn resume(replay: Replay) -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let file = replays_dir().join(format!("{secs}-{}.json", replay.seed));
+        Self {
+            start: Instant::now() - replay.duration(),
+            replay,
+            file,
+        }
+    }
+
+    /// Records the action done now
+    pub fn push(&mut self, action: ReplayAction) {
+        self.replay.push(self.start.elapsed(), action);
+    }
+
+    /// Writes the replay to the replay file
+    pub fn write(&self) -> Result<()> {
+        create_dir_all(replays_dir())?;
+        let f = BufWriter::new(File::create(&self.file)?);
+        serde_json::to_writer(f, &self.replay)?;
+        Ok(())
+    }
+}
+
+/// Loads replay from the given file
+pub fn load_replay(path: impl AsRef<Path>) -> Result<Replay> {
+    let f = BufReader::new(File::open(path)?);
+    Ok(serde_json::from_reader(f)?)
+}
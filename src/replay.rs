@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    board::{board_struct::Board, error::TextError},
+    vec2::Vec2,
+};
+
+/// Recorded game, which can be played back by applying the recorded actions
+/// to the board generated with the same seed, or to the imported board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    pub size: Vec2,
+    pub mines: usize,
+    pub seed: u64,
+    pub no_guess: bool,
+    /// Whether flags could be changed to question marks
+    #[serde(default)]
+    pub questions: bool,
+    /// Imported board in the text format, see [`Board::from_text`]
+    #[serde(default)]
+    pub layout: Option<String>,
+    pub events: Vec<ReplayEvent>,
+}
+
+/// Action recorded at given time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    /// Milliseconds since the start of the recording
+    pub time: u64,
+    pub action: ReplayAction,
+}
+
+/// Action done by the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReplayAction {
+    /// Cursor moved to the position
+    Select(Vec2),
+    Reveal(Vec2),
+    /// Reveal of already visible cell, revealing its neighbors
+    Chord(Vec2),
+    Flag(Vec2),
+    Undo,
+    Redo,
+}
+
+impl Replay {
+    /// Creates new empty [`Replay`] of the game on the given board
+    pub fn new(board: &Board) -> Self {
+        Self {
+            size: board.size,
+            mines: board.mines,
+            seed: board.seed(),
+            no_guess: board.is_no_guess(),
+            questions: board.has_questions(),
+            layout: board.is_imported().then(|| board.to_text()),
+            events: vec![],
+        }
+    }
+
+    /// Creates new board, on which the replay can be played back. Fails
+    /// when the imported board or the board parameters are invalid.
+    pub fn board(&self) -> Result<Board, TextError> {
+        let board = match &self.layout {
+            Some(layout) => Board::from_text(layout)?,
+            None => Board::new(self.size, self.mines)?
+                .with_seed(self.seed)
+                .with_no_guess(self.no_guess),
+        };
+        Ok(board.with_questions(self.questions))
+    }
+
+    /// Adds action done at given time since the start of the recording
+    pub fn push(&mut self, time: Duration, action: ReplayAction) {
+        self.events.push(ReplayEvent {
+            time: time.as_millis() as u64,
+            action,
+        });
+    }
+
+    /// Gets the time of the last event
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.events.last().map_or(0, |e| e.time))
+    }
+}
+
+impl ReplayEvent {
+    /// Gets the time since the start of the recording
+    pub fn time(&self) -> Duration {
+        Duration::from_millis(self.time)
+    }
+}
+
+impl ReplayAction {
+    /// Applies the action to the board
+    pub fn apply(&self, board: &mut Board) {
+        match *self {
+            ReplayAction::Select(pos) => board.select(pos),
+            ReplayAction::Reveal(pos) => {
+                board.select(pos);
+                board.reveal(pos);
+            }
+            ReplayAction::Chord(pos) => {
+                board.select(pos);
+                board.chord(pos);
+            }
+            ReplayAction::Flag(pos) => {
+                board.select(pos);
+                board.flag(pos);
+            }
+            ReplayAction::Undo => _ = board.undo(),
+            ReplayAction::Redo => _ = board.redo(),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    fs::{create_dir_all, remove_file, File},
+    io::{self, BufReader, BufWriter},
+    path::Path,
+};
+
+use minesweeper::{board::board_struct::Board, replay::Replay};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{config_dir, save_file},
+    error::Result,
+};
+
+/// Game saved on quit, which can be resumed later
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Save {
+    pub board: Board,
+    /// Recording of the game so far
+    #[serde(default)]
+    pub replay: Option<Replay>,
+}
+
+impl Save {
+    /// Creates new [`Save`] of the given board, pausing its timer
+    pub fn new(mut board: Board, replay: Option<Replay>) -> Self {
+        board.pause();
+        Self { board, replay }
+    }
+
+    /// Loads the save from the default save file, [`None`] when there is no
+    /// valid save
+    pub fn from_default_json() -> Option<Self> {
+        Self::from_json(save_file()).ok()
+    }
+
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Self> {
+        let f = BufReader::new(File::open(path)?);
+        let save: Save = serde_json::from_reader(f)?;
+        save.board.check()?;
+        Ok(save)
+    }
+
+    pub fn to_default_json(&self) -> Result<()> {
+        create_dir_all(config_dir())?;
+        self.to_json(save_file())
+    }
+
+    pub fn to_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        let f = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(f, self)?;
+        Ok(())
+    }
+
+    /// Removes the default save file, when it exists
+    pub fn remove_default() -> Result<()> {
+        match remove_file(save_file()) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
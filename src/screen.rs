@@ -0,0 +1,15 @@
+/// Represents which game screen is currently displayed
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Screen {
+    Game,
+    #[default]
+    DiffPicker,
+    Help,
+    Stats,
+    NameInput,
+    Leaderboard,
+    Replay,
+    Custom,
+    /// Summary of the finished game
+    Summary,
+}
@@ -0,0 +1,112 @@
+use crate::{
+    board::board_struct::Board, game_state::GameState, solver::Solver,
+    vec2::Vec2,
+};
+
+/// Game played by the built-in strategy, see [`SimulatedGame::play`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulatedGame {
+    pub win: bool,
+    /// Number of reveals, which weren't provably safe. The first reveal
+    /// isn't counted, because it's never a mine.
+    pub guesses: usize,
+    /// 3BV of the generated board, see [`Board::bbbv`]
+    pub bbbv: usize,
+}
+
+/// Summary of the games played by the built-in strategy
+#[derive(Debug, Clone, Default)]
+pub struct Simulation {
+    pub games: usize,
+    pub wins: usize,
+    /// Total number of guesses in all the games
+    pub guesses: usize,
+    /// Total 3BV of all the boards
+    pub bbbv: usize,
+}
+
+impl SimulatedGame {
+    /// Plays the game on the given board until it ends, starting by
+    /// revealing the selected cell. All the cells deduced by the [`Solver`]
+    /// are revealed or flagged. When nothing can be deduced, the cell with
+    /// the lowest probability of being a mine is revealed.
+    pub fn play(board: &mut Board) -> Self {
+        let mut guesses = 0;
+        board.reveal(board.cur);
+        while board.state().is_playing() {
+            let solver = Solver::new(board);
+            let deds = solver.deduce();
+            if deds.is_empty() {
+                guesses += 1;
+                board.reveal(guess(&solver, board));
+                continue;
+            }
+
+            for ded in deds {
+                if ded.mine {
+                    board.flag(ded.pos);
+                } else if !board[ded.pos].is_visible() {
+                    board.reveal(ded.pos);
+                }
+            }
+        }
+
+        Self {
+            win: board.state() == GameState::Win,
+            guesses,
+            bbbv: board.bbbv(),
+        }
+    }
+}
+
+impl Simulation {
+    /// Adds the played game to the summary
+    pub fn push(&mut self, game: SimulatedGame) {
+        self.games += 1;
+        self.wins += game.win as usize;
+        self.guesses += game.guesses;
+        self.bbbv += game.bbbv;
+    }
+
+    /// Gets the ratio of won games
+    pub fn win_rate(&self) -> f64 {
+        self.average(self.wins)
+    }
+
+    /// Gets the average number of guesses per game
+    pub fn avg_guesses(&self) -> f64 {
+        self.average(self.guesses)
+    }
+
+    /// Gets the average 3BV of the boards
+    pub fn avg_bbbv(&self) -> f64 {
+        self.average(self.bbbv)
+    }
+
+    fn average(&self, total: usize) -> f64 {
+        match self.games {
+            0 => 0.,
+            games => total as f64 / games as f64,
+        }
+    }
+}
+
+/// Gets the hidden cell with the lowest probability of being a mine, the
+/// first hidden cell when no probability is known
+fn guess(solver: &Solver, board: &Board) -> Vec2 {
+    let probs = solver.probabilities();
+    let best = probs
+        .iter()
+        .enumerate()
+        .filter_map(|(id, prob)| Some((id, (*prob)?)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(id, _)| id)
+        .or_else(|| {
+            board
+                .cells
+                .iter()
+                .position(|c| !c.is_visible() && !c.is_flag())
+        })
+        .unwrap_or_default();
+    Vec2::new(best % board.size.x, best / board.size.x)
+}
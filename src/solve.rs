@@ -0,0 +1,99 @@
+use std::io::{stdout, Write};
+
+use minesweeper::{
+    solver::{Certainty, Solver},
+    vec2::Vec2,
+};
+use serde::Serialize;
+
+use crate::error::Result;
+
+/// Solution of a board position - what can be proven about each hidden cell
+/// and its probability of being a mine
+#[derive(Debug, Serialize)]
+pub struct Solution {
+    pub size: Vec2,
+    pub mines: usize,
+    /// Mines without a flag, flags are considered to be correct
+    pub mines_left: isize,
+    pub cells: Vec<SolvedCell>,
+}
+
+/// Hidden cell of the [`Solution`]
+#[derive(Debug, Serialize)]
+pub struct SolvedCell {
+    /// Position of the cell, starting at 0
+    pub pos: Vec2,
+    pub certainty: Certainty,
+    /// Probability of the cell being a mine, [`None`] when it's too
+    /// expensive to compute
+    pub probability: Option<f64>,
+}
+
+/// Widths of the columns of the printed solution
+const SOLUTION_WIDTHS: [usize; 3] = [10, 14, 6];
+
+impl Solution {
+    /// Solves the position given by the solver
+    pub fn new(solver: &Solver) -> Self {
+        let size = solver.size();
+        let probs = solver.probabilities();
+        let cells = solver
+            .certainties()
+            .into_iter()
+            .zip(probs)
+            .enumerate()
+            .filter_map(|(id, (certainty, probability))| {
+                Some(SolvedCell {
+                    pos: Vec2::new(id % size.x, id / size.x),
+                    certainty: certainty?,
+                    probability,
+                })
+            })
+            .collect();
+        Self {
+            size,
+            mines: solver.mines(),
+            mines_left: solver.mines_left(),
+            cells,
+        }
+    }
+
+    /// Prints the solution as JSON
+    pub fn print_json(&self) -> Result<()> {
+        let mut out = stdout().lock();
+        serde_json::to_writer_pretty(&mut out, self)?;
+        writeln!(out)?;
+        Ok(())
+    }
+
+    /// Prints the solution as a table, positions are printed starting at 1
+    /// as in the hints
+    pub fn print(&self) {
+        println!(
+            "Board {}x{} with {} mines, {} without flag\n",
+            self.size.x, self.size.y, self.mines, self.mines_left
+        );
+        print_row(["Cell", "Certainty", "Mine"]);
+        for cell in &self.cells {
+            let prob = cell
+                .probability
+                .map_or("?".to_string(), |p| format!("{:.1}%", p * 100.));
+            print_row([
+                format!("{},{}", cell.pos.x + 1, cell.pos.y + 1),
+                format!("{:?}", cell.certainty),
+                prob,
+            ]);
+        }
+    }
+}
+
+/// Prints row of the solution table
+fn print_row<T: AsRef<str>>(cols: [T; 3]) {
+    let row: String = cols
+        .iter()
+        .zip(SOLUTION_WIDTHS)
+        .map(|(col, width)| format!("{:<width$}", col.as_ref()))
+        .collect();
+    println!("{}", row.trim_end());
+}
@@ -0,0 +1,380 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::Constraint;
+
+/// Maximum number of states in a single step of the enumeration, larger
+/// components are skipped
+const MAX_STATES: usize = 100_000;
+
+/// All the mine layouts of a connected part of the frontier, which satisfy
+/// all the numbers around it
+#[derive(Debug)]
+pub struct Component {
+    /// Ids of the cells in the component
+    pub cells: Vec<usize>,
+    /// Relative number of layouts with the given number of mines
+    pub counts: Vec<f64>,
+    /// Relative number of layouts with the given number of mines for each
+    /// cell, where the cell is a mine
+    pub mines: Vec<Vec<f64>>,
+    /// Whether the cell is a mine in all the layouts (`Some(true)`), in none
+    /// of them (`Some(false)`) or it differs (`None`)
+    pub fixed: Vec<Option<bool>>,
+}
+
+impl Component {
+    /// Splits the frontier into components, where cells are connected when
+    /// they share a number. Returns cells and constraints of each component.
+    pub fn split(
+        cons: &[Constraint],
+        len: usize,
+    ) -> Vec<(Vec<usize>, Vec<&Constraint>)> {
+        let mut parent: Vec<_> = (0..len).collect();
+        for con in cons {
+            for &cell in &con.cells[1..] {
+                let a = find(&mut parent, con.cells[0]);
+                let b = find(&mut parent, cell);
+                parent[a] = b;
+            }
+        }
+
+        let mut comps: Vec<(Vec<usize>, Vec<&Constraint>)> = Vec::new();
+        let mut comp_id = vec![usize::MAX; len];
+        for con in cons {
+            let root = find(&mut parent, con.cells[0]);
+            if comp_id[root] == usize::MAX {
+                comp_id[root] = comps.len();
+                comps.push((vec![], vec![]));
+            }
+
+            let (cells, comp_cons) = &mut comps[comp_id[root]];
+            comp_cons.push(con);
+            for &cell in &con.cells {
+                if !cells.contains(&cell) {
+                    cells.push(cell);
+                }
+            }
+        }
+        comps
+    }
+
+    /// Counts all the mine layouts of the given cells satisfying the given
+    /// constraints. Returns [`None`] when the component is too complex.
+    ///
+    /// Cells are assigned one by one and layouts are grouped by the number
+    /// of mines placed in the constraints, which have both assigned and
+    /// unassigned cells. Layouts are counted going forward and backward, so
+    /// the counts for each cell are the combination of the two.
+    pub fn enumerate(cells: Vec<usize>, cons: &[&Constraint]) -> Option<Self> {
+        let cells = order(cells, cons);
+        let enumerator = Enumerator::new(&cells, cons);
+        let len = cells.len();
+
+        let (fwd, fwd_scale) = enumerator.forward()?;
+        let (bwd, bwd_scale) = enumerator.backward(&fwd);
+
+        let mut counts = vec![0.; len + 1];
+        if let Some(poly) = fwd[len].get(&vec![]) {
+            poly.add_to(&mut counts, 1.);
+        }
+
+        let mut mines = vec![vec![0.; len + 1]; len];
+        let mut fixed = vec![None; len];
+        for id in 0..len {
+            let scale =
+                (fwd_scale[id] + bwd_scale[id + 1] - fwd_scale[len]).exp();
+            let mut has = [false; 2];
+            for (state, poly) in &fwd[id] {
+                for mine in [false, true] {
+                    let Some(next) = enumerator.next(id, state, mine) else {
+                        continue;
+                    };
+                    let Some(rest) = bwd[id + 1].get(&next) else {
+                        continue;
+                    };
+                    has[mine as usize] = true;
+                    if mine {
+                        poly.mul(rest, 1).add_to(&mut mines[id], scale);
+                    }
+                }
+            }
+            fixed[id] = match has {
+                [true, false] => Some(false),
+                [false, true] => Some(true),
+                _ => None,
+            };
+        }
+
+        Some(Self {
+            cells,
+            counts,
+            mines,
+            fixed,
+        })
+    }
+
+    /// Gets total relative number of layouts of the component
+    pub fn total(&self) -> f64 {
+        self.counts.iter().sum()
+    }
+}
+
+/// Counts of layouts by the number of mines, starting at `off` mines
+#[derive(Debug, Clone)]
+struct Poly {
+    off: usize,
+    vals: Vec<f64>,
+}
+
+impl Poly {
+    fn one() -> Self {
+        Self {
+            off: 0,
+            vals: vec![1.],
+        }
+    }
+
+    /// Adds the other counts shifted by the given number of mines
+    fn add(&mut self, other: &Poly, shift: usize) {
+        let off = other.off + shift;
+        if off < self.off {
+            let mut vals = vec![0.; self.off - off];
+            vals.append(&mut self.vals);
+            self.vals = vals;
+            self.off = off;
+        }
+        let start = off - self.off;
+        if self.vals.len() < start + other.vals.len() {
+            self.vals.resize(start + other.vals.len(), 0.);
+        }
+        for (i, v) in other.vals.iter().enumerate() {
+            self.vals[start + i] += v;
+        }
+    }
+
+    /// Combines the counts with the other counts shifted by given number of
+    /// mines
+    fn mul(&self, other: &Poly, shift: usize) -> Poly {
+        let mut vals = vec![0.; self.vals.len() + other.vals.len() - 1];
+        for (i, a) in self.vals.iter().enumerate() {
+            for (j, b) in other.vals.iter().enumerate() {
+                vals[i + j] += a * b;
+            }
+        }
+        Poly {
+            off: self.off + other.off + shift,
+            vals,
+        }
+    }
+
+    /// Adds the scaled counts to the dense counts
+    fn add_to(&self, dense: &mut [f64], scale: f64) {
+        for (i, v) in self.vals.iter().enumerate() {
+            dense[self.off + i] += v * scale;
+        }
+    }
+
+    fn max(&self) -> f64 {
+        self.vals.iter().copied().fold(0., f64::max)
+    }
+}
+
+/// Layouts grouped by the mines placed in the active constraints
+type States = HashMap<Vec<u8>, Poly>;
+
+/// Constraints of the component, with cells referenced by their order
+struct Enumerator {
+    /// Number of mines required by each constraint
+    mines: Vec<usize>,
+    /// Constraints containing each cell, with the number of its cells after
+    /// the cell
+    cell_cons: Vec<Vec<(usize, usize)>>,
+    /// Constraints with both assigned and unassigned cells before assigning
+    /// each cell
+    active: Vec<Vec<usize>>,
+}
+
+impl Enumerator {
+    fn new(cells: &[usize], cons: &[&Constraint]) -> Self {
+        let len = cells.len();
+        let pos: HashMap<_, _> =
+            cells.iter().enumerate().map(|(i, c)| (*c, i)).collect();
+
+        let mut first = Vec::new();
+        let mut last = Vec::new();
+        let mut cell_cons = vec![vec![]; len];
+        for (i, con) in cons.iter().enumerate() {
+            let mut ids: Vec<_> = con.cells.iter().map(|c| pos[c]).collect();
+            ids.sort();
+            for (j, &id) in ids.iter().enumerate() {
+                cell_cons[id].push((i, ids.len() - j - 1));
+            }
+            first.push(ids[0]);
+            last.push(ids[ids.len() - 1]);
+        }
+
+        let active = (0..=len)
+            .map(|id| {
+                (0..cons.len())
+                    .filter(|&c| first[c] < id && id <= last[c])
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            mines: cons.iter().map(|c| c.mines).collect(),
+            cell_cons,
+            active,
+        }
+    }
+
+    /// Gets the state after assigning the cell with the given state before.
+    /// Returns [`None`] when the assignment breaks any constraint.
+    fn next(&self, id: usize, state: &[u8], mine: bool) -> Option<Vec<u8>> {
+        let mut placed: Vec<(usize, usize)> = self.active[id]
+            .iter()
+            .zip(state)
+            .map(|(c, p)| (*c, *p as usize))
+            .collect();
+
+        for &(con, rest) in &self.cell_cons[id] {
+            let cur = match placed.iter_mut().find(|(c, _)| *c == con) {
+                Some((_, p)) => p,
+                None => {
+                    placed.push((con, 0));
+                    &mut placed.last_mut().unwrap().1
+                }
+            };
+            *cur += mine as usize;
+            if *cur > self.mines[con] || *cur + rest < self.mines[con] {
+                return None;
+            }
+        }
+
+        Some(
+            self.active[id + 1]
+                .iter()
+                .map(|c| {
+                    placed.iter().find(|(p, _)| p == c).map_or(0, |p| p.1)
+                        as u8
+                })
+                .collect(),
+        )
+    }
+
+    /// Counts the layouts going forward. Returns the states before each cell
+    /// and the logarithm of the scale of each step.
+    fn forward(&self) -> Option<(Vec<States>, Vec<f64>)> {
+        let len = self.active.len() - 1;
+        let mut states = vec![States::from([(vec![], Poly::one())])];
+        let mut scales = vec![0.];
+        for id in 0..len {
+            let mut next = States::new();
+            for (state, poly) in &states[id] {
+                for mine in [false, true] {
+                    let Some(ns) = self.next(id, state, mine) else {
+                        continue;
+                    };
+                    next.entry(ns)
+                        .or_insert_with(|| Poly {
+                            off: poly.off + mine as usize,
+                            vals: vec![],
+                        })
+                        .add(poly, mine as usize);
+                }
+            }
+            if next.len() > MAX_STATES {
+                return None;
+            }
+
+            scales.push(scales[id] + normalize(&mut next));
+            states.push(next);
+        }
+        Some((states, scales))
+    }
+
+    /// Counts the layouts going backward from the states reached going
+    /// forward. Returns the states before each cell and the logarithm of the
+    /// scale of each step.
+    fn backward(&self, fwd: &[States]) -> (Vec<States>, Vec<f64>) {
+        let len = fwd.len() - 1;
+        let mut states = vec![States::new(); len + 1];
+        let mut scales = vec![0.; len + 1];
+        states[len].insert(vec![], Poly::one());
+        for id in (0..len).rev() {
+            let mut cur = States::new();
+            for state in fwd[id].keys() {
+                let mut poly: Option<Poly> = None;
+                for mine in [false, true] {
+                    let Some(rest) = self
+                        .next(id, state, mine)
+                        .and_then(|ns| states[id + 1].get(&ns))
+                    else {
+                        continue;
+                    };
+                    match &mut poly {
+                        Some(p) => p.add(rest, mine as usize),
+                        None => {
+                            let mut p = rest.clone();
+                            p.off += mine as usize;
+                            poly = Some(p);
+                        }
+                    }
+                }
+                if let Some(poly) = poly {
+                    cur.insert(state.clone(), poly);
+                }
+            }
+
+            scales[id] = scales[id + 1] + normalize(&mut cur);
+            states[id] = cur;
+        }
+        (states, scales)
+    }
+}
+
+/// Scales the counts so that the largest is one, returns logarithm of the
+/// scale
+fn normalize(states: &mut States) -> f64 {
+    let max = states.values().map(Poly::max).fold(0., f64::max);
+    if max == 0. {
+        return 0.;
+    }
+    for poly in states.values_mut() {
+        poly.vals.iter_mut().for_each(|v| *v /= max);
+    }
+    max.ln()
+}
+
+/// Orders the cells, so that cells sharing a constraint are close to each
+/// other, which keeps the number of active constraints low
+fn order(cells: Vec<usize>, cons: &[&Constraint]) -> Vec<usize> {
+    let start = *cells.iter().min().unwrap();
+    let mut visited: HashMap<usize, bool> =
+        cells.iter().map(|c| (*c, false)).collect();
+    visited.insert(start, true);
+
+    let mut res = Vec::with_capacity(cells.len());
+    let mut queue = VecDeque::from([start]);
+    while let Some(cell) = queue.pop_front() {
+        res.push(cell);
+        for con in cons.iter().filter(|c| c.cells.contains(&cell)) {
+            for &next in &con.cells {
+                if !visited[&next] {
+                    visited.insert(next, true);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    res
+}
+
+/// Finds the root of the given cell in the union find
+fn find(parent: &mut [usize], mut id: usize) -> usize {
+    while parent[id] != id {
+        parent[id] = parent[parent[id]];
+        id = parent[id];
+    }
+    id
+}
@@ -0,0 +1,660 @@
+use std::fmt::Display;
+
+use frontier::Component;
+use serde::{Deserialize, Serialize};
+
+use crate::{board::board_struct::Board, vec2::Vec2};
+
+mod frontier;
+mod probability;
+
+/// Reason why the [`Solver`] deduced the cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    /// Number on the given position already has all its mines found or has
+    /// only as many hidden neighbors as mines missing
+    Single(Vec2),
+    /// Mines around the first number are a subset of the hidden neighbors of
+    /// the second number, which decides the rest of its neighbors
+    Subset(Vec2, Vec2),
+    /// Cell is the same in all the mine layouts satisfying the numbers
+    /// around it
+    Enumeration,
+    /// All the mines were found or all the hidden cells are mines
+    MineCount,
+}
+
+/// What the [`Solver`] can prove about a hidden cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Certainty {
+    Safe,
+    Mine,
+    /// Cell can be both safe and a mine, or the solver couldn't decide
+    Undetermined,
+}
+
+/// Cell deduced by the [`Solver`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deduction {
+    pub pos: Vec2,
+    pub mine: bool,
+    pub reason: Reason,
+}
+
+/// Logical solver working only with the information visible to the player -
+/// revealed numbers and flags
+#[derive(Debug, Clone)]
+pub struct Solver {
+    size: Vec2,
+    mines: usize,
+    numbers: Vec<Option<u8>>,
+    flags: Vec<bool>,
+    /// Hidden cells proven to be safe, which aren't revealed, because their
+    /// numbers are unknown
+    safe: Vec<bool>,
+}
+
+/// Number and its hidden neighbors, which contain given number of mines
+#[derive(Debug)]
+struct Constraint {
+    pos: Vec2,
+    cells: Vec<usize>,
+    mines: usize,
+}
+
+impl Solver {
+    /// Creates new [`Solver`] from the visible state of the given [`Board`].
+    /// Flags are considered to be mines.
+    pub fn new(board: &Board) -> Self {
+        let mut solver = Self::empty(board.size, board.mines);
+        for (id, cell) in board.cells.iter().enumerate() {
+            if cell.is_visible() && !cell.is_mine() {
+                solver.numbers[id] = Some(cell.get());
+            }
+            solver.flags[id] = cell.is_flag();
+        }
+        solver
+    }
+
+    /// Gets the size of the board
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Gets the number of mines on the board
+    pub fn mines(&self) -> usize {
+        self.mines
+    }
+
+    /// Gets the number of mines without a flag
+    pub fn mines_left(&self) -> isize {
+        let flags = self.flags.iter().filter(|f| **f).count();
+        self.mines as isize - flags as isize
+    }
+
+    /// Deduces cells, which are certainly safe or certainly mines. Cheaper
+    /// rules are tried first and the first rule finding anything is used.
+    pub fn deduce(&self) -> Vec<Deduction> {
+        let cons = self.constraints();
+        let mut res = self.single(&cons);
+        if res.is_empty() {
+            res = self.subset(&cons);
+        }
+        if res.is_empty() {
+            res = self.enumeration(&cons);
+        }
+        if res.is_empty() {
+            res = self.mine_count();
+        }
+        res
+    }
+
+    /// Gets single deduced cell to be used as a hint, safe cells are
+    /// preferred
+    pub fn hint(&self) -> Option<Deduction> {
+        let deds = self.deduce();
+        let safe = deds.iter().position(|d| !d.mine).unwrap_or(0);
+        deds.into_iter().nth(safe)
+    }
+
+    /// Gets what can be proven about each hidden cell using all the
+    /// deduction rules. Cells with probability exactly 0 or 1 are proven as
+    /// well, which covers the deductions using the number of mines left.
+    /// Proven cells are used for further deductions. Revealed and flagged
+    /// cells have no certainty. Cells in too large parts of the frontier may
+    /// stay undetermined, even when they could be proven.
+    pub fn certainties(&self) -> Vec<Option<Certainty>> {
+        let mut res: Vec<_> = (0..self.numbers.len())
+            .map(|id| {
+                let hidden = self.numbers[id].is_none() && !self.flags[id];
+                hidden.then_some(Certainty::Undetermined)
+            })
+            .collect();
+
+        let mut solver = self.clone();
+        loop {
+            let cons = solver.constraints();
+            let deds = [
+                solver.single(&cons),
+                solver.subset(&cons),
+                solver.enumeration(&cons),
+                solver.mine_count(),
+            ];
+
+            let mut proven = false;
+            for ded in deds.iter().flatten() {
+                let id = solver.get_id(ded.pos);
+                proven |= solver.prove(&mut res, id, ded.mine);
+            }
+            if proven {
+                continue;
+            }
+
+            for (id, prob) in solver.probabilities().into_iter().enumerate() {
+                match prob {
+                    Some(0.) => proven |= solver.prove(&mut res, id, false),
+                    Some(1.) => proven |= solver.prove(&mut res, id, true),
+                    _ => {}
+                }
+            }
+            if !proven {
+                return res;
+            }
+        }
+    }
+
+    /// Gets probability of each cell being a mine considering the revealed
+    /// numbers, flags and the number of mines left. Revealed and flagged
+    /// cells have no probability, as well as all cells when the flags
+    /// contradict the numbers.
+    ///
+    /// Cells in too large parts of the frontier have no probability either.
+    /// Their mines are unknown, so the cells outside of the frontier have no
+    /// probability then, and the too large parts are weighted as if they
+    /// were outside of the frontier when computing the other parts.
+    pub fn probabilities(&self) -> Vec<Option<f64>> {
+        let len = self.numbers.len();
+        let mut probs = vec![None; len];
+        let mut frontier = vec![false; len];
+
+        let cons = self.constraints();
+        let mut comps = Vec::new();
+        let mut skipped = 0;
+        for (cells, comp_cons) in Component::split(&cons, len) {
+            for &cell in &cells {
+                frontier[cell] = true;
+            }
+            let count = cells.len();
+            match Component::enumerate(cells, &comp_cons) {
+                Some(comp) if comp.total() == 0. => return probs,
+                Some(comp) => comps.push(comp),
+                None => skipped += count,
+            }
+        }
+
+        let other: Vec<_> = (0..len)
+            .filter(|&id| {
+                self.is_unknown(id) && !self.flags[id] && !frontier[id]
+            })
+            .collect();
+        let flags = self.flags.iter().filter(|f| **f).count();
+        let left = self.mines.saturating_sub(flags);
+
+        let Some(res) =
+            probability::calculate(&comps, other.len() + skipped, left)
+        else {
+            return probs;
+        };
+        for (comp, comp_probs) in comps.iter().zip(res.comps) {
+            for (&id, prob) in comp.cells.iter().zip(comp_probs) {
+                probs[id] = Some(prob);
+            }
+        }
+        if skipped == 0 {
+            for id in other {
+                probs[id] = Some(res.other);
+            }
+        }
+        probs
+    }
+
+    /// Checks whether the whole [`Board`] can be cleared without guessing,
+    /// when starting by revealing the given position. Board has to be
+    /// generated already.
+    pub fn is_solvable(board: &Board, start: Vec2) -> bool {
+        let mut solver = Self::empty(board.size, board.mines);
+        solver.open(board, start);
+        loop {
+            let deds = solver.deduce();
+            if deds.is_empty() {
+                break;
+            }
+            for ded in deds {
+                if ded.mine {
+                    let id = solver.get_id(ded.pos);
+                    solver.flags[id] = true;
+                } else {
+                    solver.open(board, ded.pos);
+                }
+            }
+        }
+
+        let revealed = solver.numbers.iter().filter(|n| n.is_some()).count();
+        revealed + board.mines == board.cells.len()
+    }
+}
+
+// Private methods implementations
+impl Solver {
+    /// Creates new [`Solver`] with the given numbers of the revealed cells
+    /// and flags
+    pub(crate) fn from_parts(
+        size: Vec2,
+        mines: usize,
+        numbers: Vec<Option<u8>>,
+        flags: Vec<bool>,
+    ) -> Self {
+        Self {
+            size,
+            mines,
+            safe: vec![false; numbers.len()],
+            numbers,
+            flags,
+        }
+    }
+
+    fn empty(size: Vec2, mines: usize) -> Self {
+        let len = size.x * size.y;
+        Self::from_parts(size, mines, vec![None; len], vec![false; len])
+    }
+
+    /// Marks the hidden cell as proven mine or safe, flags the mine. Returns
+    /// false when the cell was already proven.
+    fn prove(
+        &mut self,
+        res: &mut [Option<Certainty>],
+        id: usize,
+        mine: bool,
+    ) -> bool {
+        if self.flags[id] || self.safe[id] {
+            return false;
+        }
+        if mine {
+            res[id] = Some(Certainty::Mine);
+            self.flags[id] = true;
+        } else {
+            res[id] = Some(Certainty::Safe);
+            self.safe[id] = true;
+        }
+        true
+    }
+
+    /// Reveals the cell using the values from the board, opening the
+    /// neighbors of zeros as the game does
+    fn open(&mut self, board: &Board, pos: Vec2) {
+        let mut stack = vec![pos];
+        while let Some(pos) = stack.pop() {
+            let id = self.get_id(pos);
+            if self.numbers[id].is_some() || board[id].is_mine() {
+                continue;
+            }
+
+            self.numbers[id] = Some(board[id].get());
+            if board[id].get() == 0 {
+                stack.extend(board.get_neighbors(&pos));
+            }
+        }
+    }
+
+    /// Gets constraints of all the numbers with hidden neighbors
+    fn constraints(&self) -> Vec<Constraint> {
+        let mut cons = Vec::new();
+        for (id, num) in self.numbers.iter().enumerate() {
+            let Some(num) = num else {
+                continue;
+            };
+
+            let pos = self.get_pos(id);
+            let mut cells = Vec::new();
+            let mut flags = 0;
+            for n in self.neighbors(pos) {
+                let nid = self.get_id(n);
+                if self.flags[nid] {
+                    flags += 1;
+                } else if self.is_unknown(nid) {
+                    cells.push(nid);
+                }
+            }
+
+            if !cells.is_empty() {
+                cons.push(Constraint {
+                    pos,
+                    cells,
+                    mines: (*num as usize).saturating_sub(flags),
+                });
+            }
+        }
+        cons
+    }
+
+    /// Deduces cells using each number on its own
+    fn single(&self, cons: &[Constraint]) -> Vec<Deduction> {
+        let mut res = Vec::new();
+        let mut seen = vec![false; self.numbers.len()];
+        for con in cons {
+            let mine = if con.mines == 0 {
+                false
+            } else if con.mines == con.cells.len() {
+                true
+            } else {
+                continue;
+            };
+            let reason = Reason::Single(con.pos);
+            self.push_all(&mut res, &mut seen, &con.cells, mine, reason);
+        }
+        res
+    }
+
+    /// Deduces cells using pairs of neighboring numbers. When number `b`
+    /// has as many more mines than `a` as it has cells `a` doesn't have,
+    /// those cells are all mines and cells only `a` has are safe.
+    fn subset(&self, cons: &[Constraint]) -> Vec<Deduction> {
+        let mut res = Vec::new();
+        let mut seen = vec![false; self.numbers.len()];
+        for a in cons {
+            for b in cons {
+                if a.pos == b.pos
+                    || a.pos.x.abs_diff(b.pos.x) > 2
+                    || a.pos.y.abs_diff(b.pos.y) > 2
+                    || b.mines < a.mines
+                {
+                    continue;
+                }
+
+                let only_b: Vec<_> = b
+                    .cells
+                    .iter()
+                    .filter(|c| !a.cells.contains(c))
+                    .copied()
+                    .collect();
+                if only_b.len() == b.cells.len()
+                    || b.mines - a.mines != only_b.len()
+                {
+                    continue;
+                }
+
+                let only_a: Vec<_> = a
+                    .cells
+                    .iter()
+                    .filter(|c| !b.cells.contains(c))
+                    .copied()
+                    .collect();
+                let reason = Reason::Subset(a.pos, b.pos);
+                let r = reason.clone();
+                self.push_all(&mut res, &mut seen, &only_b, true, r);
+                self.push_all(&mut res, &mut seen, &only_a, false, reason);
+            }
+        }
+        res
+    }
+
+    /// Deduces cells by enumerating all the mine layouts of each connected
+    /// part of the frontier
+    fn enumeration(&self, cons: &[Constraint]) -> Vec<Deduction> {
+        let mut res = Vec::new();
+        let mut seen = vec![false; self.numbers.len()];
+        for (cells, comp_cons) in Component::split(cons, self.numbers.len()) {
+            let Some(comp) = Component::enumerate(cells, &comp_cons) else {
+                continue;
+            };
+
+            for (id, fixed) in comp.cells.iter().zip(&comp.fixed) {
+                let Some(mine) = *fixed else {
+                    continue;
+                };
+                let reason = Reason::Enumeration;
+                self.push_all(&mut res, &mut seen, &[*id], mine, reason);
+            }
+        }
+        res
+    }
+
+    /// Deduces cells using the total number of mines
+    fn mine_count(&self) -> Vec<Deduction> {
+        let hidden: Vec<_> = (0..self.numbers.len())
+            .filter(|&id| self.is_unknown(id) && !self.flags[id])
+            .collect();
+        let flags = self.flags.iter().filter(|f| **f).count();
+        let left = self.mines.saturating_sub(flags);
+
+        let mine = if left == 0 {
+            false
+        } else if left == hidden.len() {
+            true
+        } else {
+            return vec![];
+        };
+
+        let mut res = Vec::new();
+        let mut seen = vec![false; self.numbers.len()];
+        self.push_all(&mut res, &mut seen, &hidden, mine, Reason::MineCount);
+        res
+    }
+
+    /// Adds deduction for each of the given cells, which wasn't seen yet
+    fn push_all(
+        &self,
+        res: &mut Vec<Deduction>,
+        seen: &mut [bool],
+        cells: &[usize],
+        mine: bool,
+        reason: Reason,
+    ) {
+        for &id in cells {
+            if seen[id] {
+                continue;
+            }
+            seen[id] = true;
+            res.push(Deduction {
+                pos: self.get_pos(id),
+                mine,
+                reason: reason.clone(),
+            });
+        }
+    }
+
+    fn neighbors(&self, pos: Vec2) -> impl Iterator<Item = Vec2> {
+        let size = self.size;
+        (pos.y.saturating_sub(1)..(pos.y + 2).min(size.y))
+            .flat_map(move |y| {
+                (pos.x.saturating_sub(1)..(pos.x + 2).min(size.x))
+                    .map(move |x| Vec2::new(x, y))
+            })
+            .filter(move |n| *n != pos)
+    }
+
+    /// Checks whether the cell is hidden and not proven to be safe
+    fn is_unknown(&self, id: usize) -> bool {
+        self.numbers[id].is_none() && !self.safe[id]
+    }
+
+    fn get_id(&self, pos: Vec2) -> usize {
+        pos.x + pos.y * self.size.x
+    }
+
+    fn get_pos(&self, id: usize) -> Vec2 {
+        Vec2::new(id % self.size.x, id / self.size.x)
+    }
+}
+
+impl Display for Deduction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.mine { "Mine" } else { "Safe" };
+        write!(f, "{kind} at {}: ", DisplayPos(self.pos))?;
+        match (&self.reason, self.mine) {
+            (Reason::Single(pos), false) => {
+                write!(f, "number at {} has all its mines", DisplayPos(*pos))
+            }
+            (Reason::Single(pos), true) => {
+                write!(
+                    f,
+                    "number at {} needs all hidden cells",
+                    DisplayPos(*pos)
+                )
+            }
+            (Reason::Subset(a, b), _) => {
+                write!(
+                    f,
+                    "compare numbers at {} and {}",
+                    DisplayPos(*a),
+                    DisplayPos(*b)
+                )
+            }
+            (Reason::Enumeration, _) => write!(f, "in every mine layout"),
+            (Reason::MineCount, false) => write!(f, "all mines are found"),
+            (Reason::MineCount, true) => write!(f, "only mines are left"),
+        }
+    }
+}
+
+/// Displays position 1-based as `x,y`
+struct DisplayPos(Vec2);
+
+impl Display for DisplayPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.0.x + 1, self.0.y + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Gets the probabilities by trying every placement of the mines left
+    /// into the hidden cells
+    fn brute_force(solver: &Solver) -> Vec<Option<f64>> {
+        let len = solver.numbers.len();
+        let hidden: Vec<_> = (0..len)
+            .filter(|&id| solver.numbers[id].is_none() && !solver.flags[id])
+            .collect();
+        let flags = solver.flags.iter().filter(|f| **f).count();
+        let left = solver.mines - flags;
+
+        let mut total = 0;
+        let mut counts = vec![0; len];
+        let mut mines = solver.flags.clone();
+        for mask in 0..1_usize << hidden.len() {
+            if mask.count_ones() as usize != left {
+                continue;
+            }
+            for (i, &id) in hidden.iter().enumerate() {
+                mines[id] = mask >> i & 1 == 1;
+            }
+            let valid = (0..len).all(|id| {
+                solver.numbers[id].is_none_or(|num| {
+                    let pos = solver.get_pos(id);
+                    let around = solver
+                        .neighbors(pos)
+                        .filter(|n| mines[solver.get_id(*n)])
+                        .count();
+                    around == num as usize
+                })
+            });
+            if valid {
+                total += 1;
+                for &id in &hidden {
+                    counts[id] += mines[id] as usize;
+                }
+            }
+        }
+
+        let mut res = vec![None; len];
+        for id in hidden {
+            res[id] = Some(counts[id] as f64 / total as f64);
+        }
+        res
+    }
+
+    /// Gets the certainty of the cell with the given probability
+    fn certainty(prob: Option<f64>) -> Option<Certainty> {
+        prob.map(|prob| match prob {
+            0. => Certainty::Safe,
+            1. => Certainty::Mine,
+            _ => Certainty::Undetermined,
+        })
+    }
+
+    /// Gets solvers of positions from games played on small boards, each
+    /// game is played by revealing safe cells in order
+    fn positions() -> Vec<Solver> {
+        let mut res = Vec::new();
+        for seed in 0..40 {
+            let size = Vec2::new(4 + seed as usize % 2, 4);
+            let pos = |id: usize| Vec2::new(id % size.x, id / size.x);
+            let mut board = Board::new(size, 3).unwrap().with_seed(seed);
+            board.reveal(Vec2::new(1, 1));
+            if seed % 3 == 0 {
+                let mine = board.cells.iter().position(|c| c.is_mine());
+                board.flag(pos(mine.unwrap()));
+            }
+            while let Some(safe) = board
+                .cells
+                .iter()
+                .position(|c| !c.is_visible() && !c.is_mine())
+            {
+                res.push(Solver::new(&board));
+                board.reveal(pos(safe));
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn probabilities_match_brute_force() {
+        for solver in positions() {
+            let expected = brute_force(&solver);
+            for (prob, exp) in solver.probabilities().iter().zip(expected) {
+                assert_eq!(prob.is_some(), exp.is_some());
+                if let (Some(prob), Some(exp)) = (prob, exp) {
+                    assert!((prob - exp).abs() < 1e-9, "{prob} != {exp}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn certainties_match_brute_force() {
+        for solver in positions() {
+            let expected = brute_force(&solver);
+            for (cert, exp) in solver.certainties().iter().zip(expected) {
+                assert_eq!(cert.is_some(), exp.is_some());
+                assert_eq!(*cert, certainty(exp));
+            }
+        }
+    }
+
+    #[test]
+    fn deductions_are_proven() {
+        for solver in positions() {
+            let expected = brute_force(&solver);
+            for ded in solver.deduce() {
+                let exp = expected[solver.get_id(ded.pos)].unwrap();
+                assert_eq!(exp, if ded.mine { 1. } else { 0. });
+            }
+        }
+    }
+
+    #[test]
+    fn certainties_use_mine_count() {
+        let text = "5x4\nmines 3\n0001?\n0012?\n111??\n?11??\n";
+        let solver = Solver::from_text(text).unwrap();
+        let certs = solver.certainties();
+        let probs = solver.probabilities();
+        let id = solver.get_id(Vec2::new(4, 3));
+        assert_eq!(probs[id], Some(0.));
+        assert_eq!(certs[id], Some(Certainty::Safe));
+        let expected: Vec<_> =
+            brute_force(&solver).into_iter().map(certainty).collect();
+        assert_eq!(certs, expected);
+    }
+}
@@ -0,0 +1,125 @@
+use super::frontier::Component;
+
+/// Mine probabilities of the cells in each frontier component and of the
+/// hidden cells outside of the frontier
+#[derive(Debug)]
+pub struct Probabilities {
+    pub comps: Vec<Vec<f64>>,
+    pub other: f64,
+}
+
+/// Calculates probability of each cell being a mine. Layouts of the
+/// components are weighted by the number of ways the remaining mines can be
+/// placed into the `other` cells outside of the frontier. Returns [`None`]
+/// when there's no valid layout.
+pub fn calculate(
+    comps: &[Component],
+    other: usize,
+    mines: usize,
+) -> Option<Probabilities> {
+    // Scaling counts of a component doesn't change the probabilities and
+    // prevents overflows
+    let counts: Vec<Vec<f64>> = comps
+        .iter()
+        .map(|c| {
+            let max = c.counts.iter().copied().fold(0., f64::max);
+            c.counts.iter().map(|n| n / max).collect()
+        })
+        .collect();
+
+    let mut prefix = vec![vec![1.]];
+    for c in &counts {
+        prefix.push(convolve(prefix.last().unwrap(), c));
+    }
+    let mut suffix = vec![vec![1.]];
+    for c in counts.iter().rev() {
+        suffix.push(convolve(suffix.last().unwrap(), c));
+    }
+    suffix.reverse();
+
+    let all = prefix.last().unwrap();
+    let weights = weights(all.len(), other, mines);
+    let total: f64 = all.iter().zip(&weights).map(|(d, w)| d * w).sum();
+    if total == 0. || !total.is_finite() {
+        return None;
+    }
+
+    let mut res = Vec::new();
+    for (i, comp) in comps.iter().enumerate() {
+        let max = comp.counts.iter().copied().fold(0., f64::max);
+        let others = convolve(&prefix[i], &suffix[i + 1]);
+        // Weight of the component having given number of mines
+        let comp_weights: Vec<f64> = (0..comp.counts.len())
+            .map(|k| {
+                others
+                    .iter()
+                    .enumerate()
+                    .map(|(o, n)| n * weights[k + o])
+                    .sum()
+            })
+            .collect();
+
+        res.push(
+            comp.mines
+                .iter()
+                .map(|m| {
+                    let p: f64 = m
+                        .iter()
+                        .zip(&comp_weights)
+                        .map(|(n, w)| n / max * w)
+                        .sum();
+                    (p / total).clamp(0., 1.)
+                })
+                .collect(),
+        );
+    }
+
+    let mut other_prob = 0.;
+    if other > 0 {
+        let left: f64 = all
+            .iter()
+            .zip(&weights)
+            .enumerate()
+            .filter(|(k, _)| *k <= mines)
+            .map(|(k, (d, w))| d * w * (mines - k) as f64)
+            .sum();
+        other_prob = (left / total / other as f64).clamp(0., 1.);
+    }
+
+    Some(Probabilities {
+        comps: res,
+        other: other_prob,
+    })
+}
+
+/// Gets relative weights of the frontier containing `k` mines for `k` up to
+/// `len`, which is the number of ways to place the rest of the mines into the
+/// `other` cells
+fn weights(len: usize, other: usize, mines: usize) -> Vec<f64> {
+    // Logarithms of binomial coefficients `other` choose `r`
+    let mut ln_binom = vec![0.; other + 1];
+    for r in 0..other {
+        ln_binom[r + 1] =
+            ln_binom[r] + ((other - r) as f64).ln() - ((r + 1) as f64).ln();
+    }
+
+    let ln_weights: Vec<_> = (0..len + other)
+        .map(|k| match mines.checked_sub(k) {
+            Some(r) if r <= other => ln_binom[r],
+            _ => f64::NEG_INFINITY,
+        })
+        .collect();
+    let max = ln_weights.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    ln_weights.iter().map(|w| (w - max).exp()).collect()
+}
+
+/// Convolves the two distributions of mine counts
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut res = vec![0.; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            res[i + j] += x * y;
+        }
+    }
+    res
+}
@@ -0,0 +1,146 @@
+use std::{
+    fs::{create_dir_all, File},
+    io::{self, BufReader, BufWriter},
+    path::Path,
+    time::Duration,
+};
+
+use minesweeper::difficulty::Difficulty;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{config_dir, stats_file},
+    error::{Error, Result},
+};
+
+/// Statistics of the finished games for each difficulty
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(default)]
+    pub diffs: Vec<DiffStats>,
+}
+
+/// Statistics of the finished games with one difficulty
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffStats {
+    pub diff: Difficulty,
+    pub played: usize,
+    pub won: usize,
+    /// Number of games won in a row, including the last game
+    pub streak: usize,
+    pub best_streak: usize,
+    pub best_time: Option<Duration>,
+}
+
+impl Stats {
+    /// Loads the stats from the default stats file, empty stats are used
+    /// when there is no stats file. Fails when the stats file is invalid,
+    /// so it isn't overwritten by the empty stats later.
+    pub fn from_default_json() -> Result<Self> {
+        let path = stats_file();
+        match Self::from_json(&path) {
+            Err(Error::IOErr(e)) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => {
+                Err(format!("Invalid stats file `{}`: {e}", path.display())
+                    .into())
+            }
+            res => res,
+        }
+    }
+
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Self> {
+        let f = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(f)?)
+    }
+
+    pub fn to_default_json(&self) -> Result<()> {
+        create_dir_all(config_dir())?;
+        self.to_json(stats_file())
+    }
+
+    pub fn to_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        let f = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(f, self)?;
+        Ok(())
+    }
+
+    /// Records finished game with given difficulty. `time` is the time it
+    /// took to finish the game.
+    pub fn record(&mut self, diff: Difficulty, win: bool, time: Duration) {
+        if !self.diffs.iter().any(|s| s.diff == diff) {
+            self.diffs.push(DiffStats::new(diff.clone()));
+            self.diffs.sort_by_key(|s| s.diff.config());
+        }
+
+        let stats = self.diffs.iter_mut().find(|s| s.diff == diff).unwrap();
+        stats.played += 1;
+        if !win {
+            stats.streak = 0;
+            return;
+        }
+
+        stats.won += 1;
+        stats.streak += 1;
+        stats.best_streak = stats.best_streak.max(stats.streak);
+        if stats.best_time.is_none_or(|t| time < t) {
+            stats.best_time = Some(time);
+        }
+    }
+}
+
+impl DiffStats {
+    /// Creates new [`DiffStats`] with no games played
+    pub fn new(diff: Difficulty) -> Self {
+        Self {
+            diff,
+            played: 0,
+            won: 0,
+            streak: 0,
+            best_streak: 0,
+            best_time: None,
+        }
+    }
+
+    /// Gets percentage of the games won
+    pub fn win_rate(&self) -> f64 {
+        if self.played == 0 {
+            return 0.;
+        }
+        self.won as f64 / self.played as f64 * 100.
+    }
+
+    /// Gets the statistics as columns of the stats table
+    pub fn columns(&self) -> [String; 7] {
+        [
+            self.diff.to_string(),
+            self.played.to_string(),
+            self.won.to_string(),
+            format!("{:.0}%", self.win_rate()),
+            self.streak.to_string(),
+            self.best_streak.to_string(),
+            self.best_time.map_or("-".into(), format_time),
+        ]
+    }
+}
+
+/// Headers of the stats table columns
+pub const STATS_HEADER: [&str; 7] = [
+    "Difficulty",
+    "Played",
+    "Won",
+    "Win %",
+    "Streak",
+    "Max",
+    "Best",
+];
+
+/// Widths of the stats table columns
+pub const STATS_WIDTHS: [usize; 7] = [20, 8, 6, 7, 8, 5, 6];
+
+/// Formats the game time as minutes and seconds
+pub fn format_time(time: Duration) -> String {
+    let secs = time.as_secs();
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
@@ -0,0 +1,258 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+use termint::enums::Color;
+
+use crate::{
+    config::themes_dir,
+    error::{Error, Result},
+    tui::{color_mode::ColorMode, symbols::Symbols},
+};
+
+/// Names of the built-in themes
+pub const BUILTIN_THEMES: [&str; 3] = ["default", "dark", "high-contrast"];
+
+/// Colors and symbols used by the game. Colors missing in the theme file are
+/// taken from the default theme.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Colors of the numbers 1 to 8
+    pub numbers: [Color; 8],
+    /// Color of the mine in the denser densities
+    pub mine: Color,
+    pub flag: Color,
+    pub question: Color,
+    /// Color of the hinted cell marker
+    pub hint: Color,
+    /// Face of the 3D buttons, hidden cells, borders and panels
+    pub face: Color,
+    pub face_sel: Color,
+    /// Lit edge of the 3D buttons and borders
+    pub light: Color,
+    pub light_sel: Color,
+    /// Shaded edge of the 3D buttons, borders and revealed cells
+    pub shadow: Color,
+    pub shadow_sel: Color,
+    /// Background of the revealed cells
+    pub revealed: Color,
+    pub revealed_sel: Color,
+    /// Background of the hidden cells in the denser densities
+    pub hidden: Color,
+    pub hidden_sel: Color,
+    /// Background of the exploded mine
+    pub exploded: Color,
+    pub exploded_sel: Color,
+    /// Text of the top and bottom bars
+    pub bar: Color,
+    /// Text of the panels, such as help or statistics
+    pub text: Color,
+    /// Highlighted text of the panels
+    pub accent: Color,
+    pub error: Color,
+    /// Symbols aren't part of the theme file, they're set by the ASCII mode
+    #[serde(skip)]
+    pub symbols: Symbols,
+    /// Color mode the colors were converted to
+    #[serde(skip)]
+    pub colors: ColorMode,
+}
+
+impl Theme {
+    /// Gets the built-in theme with given name
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "dark" => Some(Self::dark()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Loads the theme with given name, which is either built-in or a theme
+    /// file `<name>.json` in the themes directory
+    pub fn load(name: &str) -> Result<Self> {
+        if let Some(theme) = Self::builtin(name) {
+            return Ok(theme);
+        }
+        match Self::from_json(themes_dir().join(format!("{name}.json"))) {
+            Err(Error::IOErr(e)) if e.kind() == io::ErrorKind::NotFound => {
+                Err(format!(
+                    "Unknown theme `{name}`, built-in themes are {}",
+                    BUILTIN_THEMES.join(", ")
+                )
+                .into())
+            }
+            res => res,
+        }
+    }
+
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Self> {
+        let f = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(f)?)
+    }
+
+    /// Converts the colors to the given color mode
+    pub fn with_colors(mut self, mode: ColorMode) -> Self {
+        let colors = [
+            &mut self.mine,
+            &mut self.flag,
+            &mut self.question,
+            &mut self.hint,
+            &mut self.face,
+            &mut self.face_sel,
+            &mut self.light,
+            &mut self.light_sel,
+            &mut self.shadow,
+            &mut self.shadow_sel,
+            &mut self.revealed,
+            &mut self.revealed_sel,
+            &mut self.hidden,
+            &mut self.hidden_sel,
+            &mut self.exploded,
+            &mut self.exploded_sel,
+            &mut self.bar,
+            &mut self.text,
+            &mut self.accent,
+            &mut self.error,
+        ];
+        for color in colors.into_iter().chain(self.numbers.iter_mut()) {
+            *color = mode.convert(*color);
+        }
+        self.colors = mode;
+        self
+    }
+
+    /// Whether the widgets are drawn flat, because the 3D effect needs
+    /// Unicode blocks and colors
+    pub fn flat(&self) -> bool {
+        self.symbols.ascii || self.colors == ColorMode::Mono
+    }
+
+    /// Gets the face, shadow and light colors of the 3D button
+    pub fn button(&self, selected: bool) -> (Color, Color, Color) {
+        match selected {
+            true => (self.face_sel, self.shadow_sel, self.light_sel),
+            false => (self.face, self.shadow, self.light),
+        }
+    }
+
+    /// Dark theme
+    fn dark() -> Self {
+        Self {
+            numbers: [
+                Color::Hex(0x5f87ff),
+                Color::Hex(0x5fd75f),
+                Color::Hex(0xff5f5f),
+                Color::Hex(0xaf87ff),
+                Color::Hex(0xd78700),
+                Color::Hex(0x5fd7d7),
+                Color::Hex(0xe4e4e4),
+                Color::Hex(0x9e9e9e),
+            ],
+            mine: Color::Hex(0xe4e4e4),
+            flag: Color::Hex(0xff5f5f),
+            question: Color::Hex(0xe4e4e4),
+            hint: Color::Hex(0x87afff),
+            face: Color::Hex(0x3a3a3a),
+            face_sel: Color::Hex(0x4e4e4e),
+            light: Color::Hex(0x5a5a5a),
+            light_sel: Color::Hex(0x6c6c6c),
+            shadow: Color::Hex(0x1c1c1c),
+            shadow_sel: Color::Hex(0x262626),
+            revealed: Color::Hex(0x262626),
+            revealed_sel: Color::Hex(0x3a3a3a),
+            hidden: Color::Hex(0x4e4e4e),
+            hidden_sel: Color::Hex(0x626262),
+            exploded: Color::Hex(0xaf0000),
+            exploded_sel: Color::Hex(0x870000),
+            bar: Color::Hex(0xd0d0d0),
+            text: Color::Hex(0xc6c6c6),
+            accent: Color::Hex(0x87afff),
+            error: Color::Hex(0xff5f5f),
+            symbols: Symbols::default(),
+            colors: ColorMode::default(),
+        }
+    }
+
+    /// High contrast theme with yellow selection
+    fn high_contrast() -> Self {
+        Self {
+            numbers: [
+                Color::Hex(0x0000ff),
+                Color::Hex(0x008000),
+                Color::Hex(0xff0000),
+                Color::Hex(0x000080),
+                Color::Hex(0x800000),
+                Color::Hex(0x008080),
+                Color::Hex(0x000000),
+                Color::Hex(0x404040),
+            ],
+            mine: Color::Hex(0x000000),
+            flag: Color::Hex(0xff0000),
+            question: Color::Hex(0x000000),
+            hint: Color::Hex(0xff00ff),
+            face: Color::Hex(0xd0d0d0),
+            face_sel: Color::Hex(0xffff00),
+            light: Color::Hex(0xffffff),
+            light_sel: Color::Hex(0xffffff),
+            shadow: Color::Hex(0x000000),
+            shadow_sel: Color::Hex(0x000000),
+            revealed: Color::Hex(0xffffff),
+            revealed_sel: Color::Hex(0xffff00),
+            hidden: Color::Hex(0x808080),
+            hidden_sel: Color::Hex(0xffff00),
+            exploded: Color::Hex(0xff0000),
+            exploded_sel: Color::Hex(0xff8000),
+            bar: Color::Hex(0x000000),
+            text: Color::Hex(0x000000),
+            accent: Color::Hex(0x0000ff),
+            error: Color::Hex(0xff0000),
+            symbols: Symbols::default(),
+            colors: ColorMode::default(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            numbers: [
+                Color::Hex(0x0000ff),
+                Color::Hex(0x007700),
+                Color::Hex(0xff0000),
+                Color::Hex(0x000077),
+                Color::Hex(0x770000),
+                Color::Hex(0x007777),
+                Color::Hex(0x000000),
+                Color::Hex(0x777777),
+            ],
+            mine: Color::Hex(0x000000),
+            flag: Color::Hex(0xff0000),
+            question: Color::Hex(0x000000),
+            hint: Color::Hex(0x0000ff),
+            face: Color::Hex(0xbcbcbc),
+            face_sel: Color::Hex(0x999999),
+            light: Color::Hex(0xffffff),
+            light_sel: Color::Hex(0xcccccc),
+            shadow: Color::Hex(0x797979),
+            shadow_sel: Color::Hex(0x696969),
+            revealed: Color::Hex(0xbcbcbc),
+            revealed_sel: Color::Hex(0xa0a0a0),
+            hidden: Color::Hex(0x8a8a8a),
+            hidden_sel: Color::Hex(0x696969),
+            exploded: Color::Hex(0xee0000),
+            exploded_sel: Color::Hex(0xd20000),
+            bar: Color::Hex(0x303030),
+            text: Color::Hex(0x404040),
+            accent: Color::Hex(0x0000ff),
+            error: Color::Hex(0xaf0000),
+            symbols: Symbols::default(),
+            colors: ColorMode::default(),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use minesweeper::{board::board_struct::Board, vec2::Vec2};
+use termint::{
+    geometry::Constraint,
+    prelude::MouseButton,
+    widgets::{Button, Grid, Layout},
+};
+
+use crate::{message::Message, theme::Theme, tui::Element};
+
+use super::{cell::CellView, density::Density};
+
+/// Gets the element of the visible part of the board, starting at `start`
+/// with `view` cells, rendered with the given `density` and `theme`. Cell
+/// under the cursor is highlighted only when `selectable` is true, `hint` is
+/// the position of the hinted cell and `probs` are the displayed mine
+/// probabilities of the cells.
+pub fn board_element(
+    board: &Board,
+    (start, view): (Vec2, Vec2),
+    density: Density,
+    theme: &Theme,
+    selectable: bool,
+    hint: Option<Vec2>,
+    probs: Option<&[Option<f64>]>,
+) -> Element {
+    let (width, height) = density.cell_size();
+    let mut grid = Grid::new(vec![width; view.x], vec![height; view.y]);
+    for vy in 0..view.y {
+        for vx in 0..view.x {
+            let (x, y) = (start.x + vx, start.y + vy);
+            let pos = Vec2::new(x, y);
+            let sel = selectable && board.cur == pos;
+            let view = CellView::new(&board[pos], sel, theme)
+                .hint(hint == Some(pos))
+                .prob(probs.and_then(|p| p[board.get_id(x, y)]))
+                .density(density);
+            let button = Button::new(view.element())
+                .on_click(Message::CellReveal(pos))
+                .on_press(MouseButton::Right, Message::CellFlag(pos))
+                .on_press(MouseButton::Middle, Message::CellChord(pos));
+            grid.push(button, vx, vy);
+        }
+    }
+
+    let mut layout = Layout::horizontal().center();
+    layout.push(grid, Constraint::Length(view.x * width));
+    layout.into()
+}
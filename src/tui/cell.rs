@@ -0,0 +1,302 @@
+use minesweeper::board::cell::{Cell, CellType};
+use termint::{
+    buffer::Buffer,
+    enums::{Color, Modifier, Wrap},
+    geometry::Vec2,
+    style::Style,
+    widgets::{LayoutNode, Span, Widget},
+};
+
+use crate::{
+    message::Message,
+    theme::Theme,
+    tui::{density::Density, widgets::button::Button, Element},
+};
+
+/// View of [`Cell`] of the board, which creates its element
+#[derive(Debug, Clone)]
+pub struct CellView<'a> {
+    cell: Cell,
+    sel: bool,
+    hint: bool,
+    prob: Option<f64>,
+    density: Density,
+    theme: &'a Theme,
+}
+
+/// Widget rendering revealed [`Cell`] in the regular density
+#[derive(Debug, Clone)]
+struct RevealedCell {
+    val: &'static str,
+    fg: Color,
+    bg: Color,
+    edge: Color,
+    modifier: Modifier,
+    flat: bool,
+}
+
+impl<'a> CellView<'a> {
+    /// Creates new [`CellView`] of the given [`Cell`] with colors of given
+    /// theme
+    pub fn new(cell: &Cell, sel: bool, theme: &'a Theme) -> Self {
+        Self {
+            cell: cell.clone(),
+            sel,
+            hint: false,
+            prob: None,
+            density: Density::default(),
+            theme,
+        }
+    }
+
+    /// Sets whether the cell is highlighted as a hint
+    pub fn hint(mut self, hint: bool) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Sets the probability of the cell being a mine, which is displayed on
+    /// hidden cells
+    pub fn prob(mut self, prob: Option<f64>) -> Self {
+        self.prob = prob;
+        self
+    }
+
+    /// Sets the density the cell is rendered with
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Gets the corresponding cell element
+    pub fn element(self) -> Element {
+        match self.cell.cell_type {
+            _ if self.density != Density::Regular => self.get_flat().into(),
+            CellType::Visible => self.get_revealed().into(),
+            _ => self.get_hidden().into(),
+        }
+    }
+}
+
+impl Widget<Message> for RevealedCell {
+    fn render(&self, buffer: &mut Buffer, node: &LayoutNode) {
+        let (lb, db) = (self.edge, self.bg);
+
+        let mut pos = *node.area.pos();
+        if self.flat {
+            buffer.set_str_styled("      ", &pos, Style::new().bg(db));
+            buffer.set_bg(lb, &pos);
+        } else {
+            buffer.set_str_styled(" ▆▆▆▆▆", &pos, Style::new().bg(lb).fg(db));
+        }
+
+        pos.y += 1;
+        buffer.set_str_styled(
+            format!("   {} ", self.val),
+            &pos,
+            Style::new().bg(db).fg(self.fg).modifier(self.modifier),
+        );
+        buffer.set_bg(lb, &pos);
+
+        pos.y += 1;
+        let bot = if self.flat {
+            "      "
+        } else {
+            " ▂▂▂▂▂"
+        };
+        buffer.set_str_styled(bot, &pos, Style::new().bg(db).fg(lb));
+        buffer.set_bg(lb, &pos);
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        3
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        7
+    }
+
+    fn children(&self) -> Vec<&Element> {
+        vec![]
+    }
+}
+
+impl CellView<'_> {
+    fn get_revealed(&self) -> RevealedCell {
+        let (val, fg) = self.get_value();
+        RevealedCell {
+            val,
+            fg,
+            bg: self.get_bg(),
+            edge: self.theme.shadow,
+            modifier: self.get_modifier(),
+            flat: self.theme.flat(),
+        }
+    }
+
+    /// Gets background of the revealed cell or of the flat hidden cell
+    fn get_bg(&self) -> Color {
+        let theme = self.theme;
+        let visible = self.cell.is_visible();
+        match self.sel {
+            true if self.cell.is_exploded() => theme.exploded_sel,
+            true if visible => theme.revealed_sel,
+            true => theme.hidden_sel,
+            false if self.cell.is_exploded() => theme.exploded,
+            false if visible => theme.revealed,
+            false => theme.hidden,
+        }
+    }
+
+    fn get_hidden(&self) -> Button<Message> {
+        let symbols = &self.theme.symbols;
+        let text = match self.cell.cell_type {
+            CellType::Flag | CellType::WrongFlag => {
+                Span::new(format!(" {} ", symbols.flag)).fg(self.theme.flag)
+            }
+            _ if self.hint => {
+                Span::new(format!(" {} ", symbols.hint)).fg(self.theme.hint)
+            }
+            CellType::Question => Span::new(" ? ").fg(self.theme.question),
+            _ if self.prob.is_some() => self.get_prob(),
+            _ => Span::new("   "),
+        };
+        let text = text.modifier(self.get_modifier()).wrap(Wrap::Letter);
+        Button::new(text, self.theme).selected(self.sel)
+    }
+
+    /// Gets the flat single line cell used by the denser densities. Mine
+    /// probabilities are shown as percentages only when there's space for
+    /// them, otherwise the cell is colored by the probability.
+    fn get_flat(&self) -> Span {
+        let (width, _) = self.density.cell_size();
+        let symbols = &self.theme.symbols;
+        let mut bg = self.get_bg();
+
+        let span = match self.cell.cell_type {
+            CellType::Visible => {
+                let (val, fg) = self.get_value();
+                let text = match val {
+                    // Unicode mine is 2 columns wide
+                    val if val == symbols.mine && !symbols.ascii => {
+                        match width {
+                            ..2 => center("*", 1, width),
+                            _ => center(val, 2, width),
+                        }
+                    }
+                    val => {
+                        let first = val.chars().next().unwrap_or(' ');
+                        center(&first.to_string(), 1, width)
+                    }
+                };
+                Span::new(text).fg(fg)
+            }
+            CellType::Flag | CellType::WrongFlag => {
+                Span::new(center(symbols.flag, 1, width)).fg(self.theme.flag)
+            }
+            _ if self.hint => {
+                Span::new(center(symbols.hint, 1, width)).fg(self.theme.hint)
+            }
+            CellType::Question => {
+                Span::new(center("?", 1, width)).fg(self.theme.question)
+            }
+            _ if self.prob.is_some() && width >= 3 => self.get_prob(),
+            _ => {
+                if let Some(prob) = self.prob {
+                    bg = self.theme.colors.convert(prob_color(prob));
+                }
+                // Without colors hidden cells can't be told apart from the
+                // empty revealed cells
+                match self.theme.colors.modifiers() {
+                    true => Span::new(center(symbols.hidden, 1, width)),
+                    false => Span::new(" ".repeat(width)),
+                }
+            }
+        };
+        span.bg(bg).modifier(self.get_modifier()).wrap(Wrap::Letter)
+    }
+
+    /// Gets probability of the cell being a mine as a percentage colored
+    /// from green to red
+    fn get_prob(&self) -> Span {
+        let prob = self.prob.unwrap_or_default();
+        let text = match (prob * 100.).round() as u8 {
+            0 if prob > 0. => "<1%".to_string(),
+            100 if prob < 1. => "99%".to_string(),
+            100 => "100".to_string(),
+            p => format!("{p:>2}%"),
+        };
+        Span::new(text)
+            .fg(self.theme.colors.convert(prob_color(prob)))
+            .wrap(Wrap::Letter)
+    }
+
+    /// Gets the modifiers of the cell. When the colors may not be
+    /// distinguishable, the selection, flags, numbers and exploded mine are
+    /// shown by modifiers too.
+    fn get_modifier(&self) -> Modifier {
+        let mut modifier = Modifier::empty();
+        if self.cell.cell_type == CellType::WrongFlag {
+            modifier |= Modifier::STRIKED;
+        }
+        if !self.theme.colors.modifiers() {
+            return modifier;
+        }
+
+        if self.sel {
+            modifier |= Modifier::INVERSED;
+        }
+        if self.cell.is_exploded() {
+            modifier |= Modifier::UNDERLINED | Modifier::BOLD;
+        }
+        match self.cell.cell_type {
+            CellType::Flag | CellType::WrongFlag => modifier |= Modifier::BOLD,
+            CellType::Visible if (0x01..=0x08).contains(&self.cell.value) => {
+                modifier |= Modifier::BOLD
+            }
+            _ => {}
+        }
+        modifier
+    }
+
+    fn get_value(&self) -> (&'static str, Color) {
+        const VALUES: [&str; 8] =
+            ["1 ", "2 ", "3 ", "4 ", "5 ", "6 ", "7 ", "8 "];
+        match self.cell.value {
+            v @ 0x01..=0x08 => {
+                let id = v as usize - 1;
+                (VALUES[id], self.theme.numbers[id])
+            }
+            0xfe | 0xff => (self.theme.symbols.mine, self.theme.mine),
+            _ => ("  ", Color::Default),
+        }
+    }
+}
+
+/// Gets the color of the mine probability, from green to red
+fn prob_color(prob: f64) -> Color {
+    Color::Rgb(
+        (0xee as f64 * prob) as u8,
+        (0x77 as f64 * (1. - prob)) as u8,
+        0,
+    )
+}
+
+/// Centers the text `len` columns wide in the given number of columns
+fn center(text: &str, len: usize, width: usize) -> String {
+    let left = width.saturating_sub(len) / 2;
+    let right = width.saturating_sub(len + left);
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+}
+
+impl From<RevealedCell> for Element {
+    fn from(value: RevealedCell) -> Self {
+        Element::new(value)
+    }
+}
+
+impl From<RevealedCell> for Box<dyn Widget<Message>> {
+    fn from(value: RevealedCell) -> Self {
+        Box::new(value)
+    }
+}
@@ -0,0 +1,141 @@
+use std::env;
+
+use serde::{Deserialize, Serialize};
+use termint::enums::{Color, RGB};
+
+/// Colors supported by the terminal, the theme colors are converted to the
+/// nearest supported colors
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum ColorMode {
+    /// 24-bit RGB colors
+    #[default]
+    TrueColor,
+    /// 256 indexed colors
+    Ansi256,
+    /// 16 basic ANSI colors
+    Ansi16,
+    /// No colors, only modifiers such as bold or reverse
+    Mono,
+}
+
+/// Basic ANSI colors with their RGB values in the VGA palette
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (170, 0, 0)),
+    (Color::DarkGreen, (0, 170, 0)),
+    (Color::DarkYellow, (170, 85, 0)),
+    (Color::DarkBlue, (0, 0, 170)),
+    (Color::DarkMagenta, (170, 0, 170)),
+    (Color::DarkCyan, (0, 170, 170)),
+    (Color::LightGray, (170, 170, 170)),
+    (Color::Gray, (85, 85, 85)),
+    (Color::Red, (255, 85, 85)),
+    (Color::Green, (85, 255, 85)),
+    (Color::Yellow, (255, 255, 85)),
+    (Color::Blue, (85, 85, 255)),
+    (Color::Magenta, (255, 85, 255)),
+    (Color::Cyan, (85, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+impl ColorMode {
+    /// Detects the color mode from the `NO_COLOR`, `COLORTERM` and `TERM`
+    /// environment variables
+    pub fn detect() -> Self {
+        if env::var("NO_COLOR").is_ok_and(|v| !v.is_empty()) {
+            return Self::Mono;
+        }
+        if let Ok("truecolor" | "24bit") = env::var("COLORTERM").as_deref() {
+            return Self::TrueColor;
+        }
+        match env::var("TERM") {
+            Ok(term) if term == "dumb" => Self::Mono,
+            Ok(term) if term.contains("256color") => Self::Ansi256,
+            Ok(term) if !term.is_empty() => Self::Ansi16,
+            // Terminals without `TERM`, such as on Windows, support RGB
+            _ => Self::TrueColor,
+        }
+    }
+
+    /// Whether the selected cell, flags, numbers and exploded mines are
+    /// also shown by modifiers, because the colors may not differ enough
+    pub fn modifiers(&self) -> bool {
+        *self != Self::TrueColor
+    }
+
+    /// Converts the color to the nearest color supported in this mode
+    pub fn convert(&self, color: Color) -> Color {
+        match self {
+            Self::TrueColor => color,
+            Self::Mono => Color::Default,
+            Self::Ansi256 => match rgb(color) {
+                Some(rgb) if !matches!(color, Color::Indexed(_)) => {
+                    Color::Indexed(to_indexed(rgb))
+                }
+                _ => color,
+            },
+            Self::Ansi16 => match color {
+                Color::Indexed(i @ 0..=15) => ANSI16[i as usize].0,
+                color => rgb(color).map_or(color, to_ansi16),
+            },
+        }
+    }
+}
+
+/// Gets the RGB value of the color, [`None`] for the basic ANSI colors
+fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let rgb = match color {
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Hex(hex) => RGB::from_hex(hex),
+        Color::Hsl(h, s, l) => RGB::from_hsl(h, s, l),
+        Color::Indexed(i @ 16..=231) => {
+            let level = |v: u8| if v == 0 { 0 } else { v * 40 + 55 };
+            let i = i - 16;
+            return Some((level(i / 36), level(i / 6 % 6), level(i % 6)));
+        }
+        Color::Indexed(i @ 232..) => {
+            let v = (i - 232) * 10 + 8;
+            return Some((v, v, v));
+        }
+        _ => return None,
+    };
+    Some((rgb.r, rgb.g, rgb.b))
+}
+
+/// Gets the nearest color of the 256 color palette
+fn to_indexed((r, g, b): (u8, u8, u8)) -> u8 {
+    // Levels of the 6x6x6 color cube are 0, 95, 135, 175, 215 and 255
+    let level = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        v => (v - 35) / 40,
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    // Levels of the grayscale are 8, 18, ..., 238
+    let avg = (r as u16 + g as u16 + b as u16) / 3;
+    let gray = 232 + (avg.saturating_sub(3) / 10).min(23) as u8;
+
+    let diff =
+        |i| rgb(Color::Indexed(i)).map_or(u32::MAX, |c| dist(c, (r, g, b)));
+    if diff(gray) < diff(cube) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Gets the nearest basic ANSI color
+fn to_ansi16(rgb: (u8, u8, u8)) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, c)| dist(*c, rgb))
+        .map_or(Color::Default, |(color, _)| *color)
+}
+
+/// Gets the squared distance of the colors
+fn dist(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
@@ -0,0 +1,135 @@
+use minesweeper::difficulty::Difficulty;
+use termint::{
+    geometry::Constraint,
+    prelude::{KeyCode, KeyEvent},
+    term::Action,
+    widgets::{Layout, ToSpan},
+};
+
+use crate::{
+    app::App,
+    message::Message,
+    screen::Screen,
+    tui::{
+        widgets::{border::Border, button::Button},
+        Element,
+    },
+};
+
+/// Labels of the custom difficulty form fields
+const LABELS: [&str; 3] = ["Width", "Height", "Mines"];
+/// Maximum number of digits of the form fields
+const MAX_DIGITS: usize = 4;
+
+/// Form for creating custom difficulty
+#[derive(Debug, Default)]
+pub struct CustomForm {
+    /// Entered width, height and number of mines
+    pub fields: [String; 3],
+    /// Index of the selected field
+    pub sel: usize,
+}
+
+impl CustomForm {
+    /// Creates new [`CustomForm`] filled with the given difficulty
+    pub fn new(diff: &Difficulty) -> Self {
+        let (w, h, m) = diff.config();
+        Self {
+            fields: [w.to_string(), h.to_string(), m.to_string()],
+            sel: 0,
+        }
+    }
+
+    /// Gets the entered difficulty or the reason why it's invalid
+    pub fn difficulty(&self) -> Result<Difficulty, String> {
+        let mut vals = [0usize; 3];
+        for ((val, field), label) in
+            vals.iter_mut().zip(&self.fields).zip(LABELS)
+        {
+            *val = field
+                .parse()
+                .map_err(|_| format!("{label} has to be a number"))?;
+        }
+
+        let [width, height, mines] = vals;
+        let diff = Difficulty::Custom {
+            width,
+            height,
+            mines,
+        };
+        diff.validate().map_err(|e| e.to_string())?;
+        Ok(diff)
+    }
+}
+
+impl App {
+    /// Renders the custom difficulty form
+    pub fn render_custom(&self) -> Element {
+        let mut layout = Layout::vertical();
+        for (id, (label, field)) in
+            LABELS.iter().zip(&self.form.fields).enumerate()
+        {
+            let sel = id == self.form.sel;
+            let cursor = if sel { "_" } else { "" };
+            let mut row = Layout::horizontal();
+            row.push(label.fg(self.theme.bar), Constraint::Length(8));
+            row.push(
+                format!("{field}{cursor}").fg(self.theme.bar),
+                Constraint::Fill(1),
+            );
+            let btn = Button::<Message>::new(row, &self.theme).selected(sel);
+            layout.push(btn, Constraint::Min(3));
+        }
+
+        let status = match self.form.difficulty() {
+            Ok(_) => "Enter to start".fg(self.theme.bar),
+            Err(e) => e.fg(self.theme.error),
+        };
+        let border = Border::new(layout, false, &self.theme)
+            .top_bar("Custom".fg(self.theme.bar))
+            .bot_bar(status);
+
+        let mut wrapper = Layout::vertical().center();
+        wrapper.push(border, Constraint::Min(0));
+        let mut main = Layout::horizontal().center();
+        main.push(wrapper, Constraint::Length(33));
+        main.into()
+    }
+
+    /// Custom difficulty form key listener
+    pub fn listen_custom(&mut self, event: KeyEvent) -> Action {
+        let field = &mut self.form.fields[self.form.sel];
+        match event.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                if field.len() < MAX_DIGITS {
+                    field.push(c);
+                }
+            }
+            KeyCode::Backspace => _ = field.pop(),
+            KeyCode::Up => self.form.sel = self.form.sel.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => {
+                self.form.sel = (self.form.sel + 1) % LABELS.len()
+            }
+            KeyCode::Enter => self.start_custom(),
+            KeyCode::Esc => self.screen = Screen::DiffPicker,
+            _ => return Action::NONE,
+        }
+        Action::RENDER
+    }
+
+    /// Starts the game with the entered difficulty, when it's valid, and
+    /// remembers it in the config
+    fn start_custom(&mut self) {
+        let Ok(diff) = self.form.difficulty() else {
+            return;
+        };
+
+        if self.new_board(diff.clone()).is_err() {
+            return;
+        }
+        self.config.custom = Some(diff);
+        if let Err(e) = self.config.to_default_json() {
+            self.msg = Some(format!("Failed to save config: {e}"));
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// How densely the board cells are rendered
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum Density {
+    /// 3D cells, 6 columns and 3 lines each
+    #[default]
+    Regular,
+    /// Flat cells, 3 columns and 1 line each
+    Compact,
+    /// Single character cells, 2 columns and 1 line each
+    Dense,
+    /// Single character cells, 1 column and 1 line each
+    Tiny,
+}
+
+impl Density {
+    /// Gets the number of columns and lines taken by a rendered cell
+    pub fn cell_size(&self) -> (usize, usize) {
+        match self {
+            Density::Regular => (6, 3),
+            Density::Compact => (3, 1),
+            Density::Dense => (2, 1),
+            Density::Tiny => (1, 1),
+        }
+    }
+
+    /// Gets the next density, cycling back to [`Density::Regular`]
+    pub fn next(&self) -> Self {
+        match self {
+            Density::Regular => Density::Compact,
+            Density::Compact => Density::Dense,
+            Density::Dense => Density::Tiny,
+            Density::Tiny => Density::Regular,
+        }
+    }
+}
+
+impl Display for Density {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Density::Regular => write!(f, "Regular"),
+            Density::Compact => write!(f, "Compact"),
+            Density::Dense => write!(f, "Dense"),
+            Density::Tiny => write!(f, "Tiny"),
+        }
+    }
+}
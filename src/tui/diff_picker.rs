@@ -1,23 +1,29 @@
+use minesweeper::difficulty::Difficulty;
 use termint::{
-    enums::Color,
-    geometry::{Constraint, TextAlign, Vec2},
-    prelude::{KeyCode, KeyEvent},
+    geometry::{Constraint, TextAlign},
+    prelude::KeyEvent,
     term::Action,
     widgets::{Button as TButton, Layout, ToSpan},
 };
 
 use crate::{
     app::App,
-    args::Difficulty,
-    board::board_struct::Board,
-    game_state::Screen,
+    keys::{KeyAction, PICKER_ACTIONS},
     message::Message,
+    save::Save,
+    screen::Screen,
     tui::{
+        custom::CustomForm,
         widgets::{border::Border, button::Button},
         Element,
     },
 };
 
+/// Id of the custom difficulty button in the difficulty picker
+const CUSTOM_ID: usize = 3;
+/// Id of the resume button in the difficulty picker
+const RESUME_ID: usize = 4;
+
 impl App {
     /// Renders difficulty picker
     pub fn render_dp(&self) -> Element {
@@ -25,9 +31,16 @@ impl App {
         layout.push(self.get_button("Easy", 0), Constraint::Min(3));
         layout.push(self.get_button("Medium", 1), Constraint::Min(3));
         layout.push(self.get_button("Hard", 2), Constraint::Min(3));
+        layout.push(self.get_button("Custom", CUSTOM_ID), Constraint::Min(3));
+        if self.saved.is_some() {
+            layout.push(
+                self.get_button("Resume", RESUME_ID),
+                Constraint::Min(3),
+            );
+        }
 
-        let border = Border::new(layout, false)
-            .top_bar("Minesweeper".fg(Color::Hex(0x303030)));
+        let border = Border::new(layout, false, &self.theme)
+            .top_bar("Minesweeper".fg(self.theme.bar));
 
         let mut wrapper = Layout::vertical().center();
         wrapper.push(border, Constraint::Min(0));
@@ -38,15 +51,19 @@ impl App {
 
     /// Difficulty picker key listener
     pub fn listen_dp(&mut self, event: KeyEvent) -> Action {
-        match event.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+        match self.config.keys.get(&event, &PICKER_ACTIONS) {
+            Some(KeyAction::Up) => {
                 self.picker_state = self.picker_state.saturating_sub(1)
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                self.picker_state += (self.picker_state < 2) as usize
+            Some(KeyAction::Down) => {
+                let last = match self.saved {
+                    Some(_) => RESUME_ID,
+                    None => CUSTOM_ID,
+                };
+                self.picker_state += (self.picker_state < last) as usize
             }
-            KeyCode::Enter => self.eval_diff(self.picker_state),
-            KeyCode::Esc | KeyCode::Char('q') => return Action::QUIT,
+            Some(KeyAction::Confirm) => self.eval_diff(self.picker_state),
+            Some(KeyAction::Quit) => return Action::QUIT,
             _ => return Action::NONE,
         };
         Action::RENDER
@@ -60,17 +77,35 @@ impl App {
         Action::RENDER
     }
 
+    /// Offers resuming the given saved game in the difficulty picker
+    pub fn offer_resume(&mut self, save: Save) {
+        self.saved = Some(save);
+        self.picker_state = RESUME_ID;
+    }
+
     fn eval_diff(&mut self, id: usize) {
-        let diff = Difficulty::from_index(id);
-        let (w, h, m) = diff.config();
-        self.board = Board::new(Vec2::new(w, h), m);
-        self.screen = Screen::Game;
+        match id {
+            CUSTOM_ID => {
+                let diff = self.config.custom.clone().unwrap_or_default();
+                self.form = CustomForm::new(&diff);
+                self.screen = Screen::Custom;
+            }
+            RESUME_ID => {
+                if let Some(save) = self.saved.take() {
+                    self.picker_state = 0;
+                    self.resume(save);
+                }
+            }
+            // The preset difficulties are always valid
+            _ => _ = self.new_board(Difficulty::from_index(id)),
+        }
     }
 
     /// Difficulty picker button getter
     fn get_button(&self, text: &str, id: usize) -> TButton<Message> {
         let btn = Button::new(
-            text.fg(Color::Hex(0x303030)).align(TextAlign::Center),
+            text.fg(self.theme.bar).align(TextAlign::Center),
+            &self.theme,
         )
         .selected(id == self.picker_state);
         TButton::new(btn).on_click(Message::DiffSel(id))
@@ -1,76 +1,149 @@
+use minesweeper::{
+    game_state::GameState, replay::ReplayAction, solver::Solver, vec2::Vec2,
+};
 use termint::{
-    enums::Color,
     geometry::Constraint,
-    prelude::{KeyCode, KeyEvent},
+    prelude::{KeyEvent, MouseButton},
     term::Action,
     widgets::{Layout, Spacer, ToSpan},
 };
 
 use crate::{
     app::App,
-    game_state::{GameState, Screen},
+    board_file::export_board,
+    keys::{KeyAction, GAME_ACTIONS},
+    leaderboard::Score,
     message::Message,
+    recorder::Recorder,
+    screen::Screen,
+    stats::format_time,
     tui::Element,
 };
 
-use super::widgets::border::Border;
+use super::{
+    board::board_element,
+    viewport::{BORDER_HEIGHT, BORDER_WIDTH, MIN_WIDTH},
+    widgets::border::Border,
+};
 
 impl App {
     pub fn render_game(&self) -> Element {
-        let help = "🛈 Press i for help".fg(Color::Hex(0x303030));
+        let help = if let Some(hint) = &self.hint {
+            hint.to_string().fg(self.theme.bar)
+        } else if let Some(msg) = &self.msg {
+            msg.as_str().fg(self.theme.bar)
+        } else if self.board.is_no_guess()
+            && self.board.is_generated()
+            && !self.board.is_guess_free()
+        {
+            "No guess-free board found, guessing may be needed"
+                .fg(self.theme.bar)
+        } else {
+            let symbols = &self.theme.symbols;
+            let key =
+                self.config.keys.label(&[KeyAction::Help], symbols.ascii);
+            format!("{}Press {key} for help", symbols.info).fg(self.theme.bar)
+        };
 
-        let grid = self.board.get_element();
-        let border = Border::new(grid, false)
+        let hint = self.hint.as_ref().map(|h| h.pos);
+        let grid = board_element(
+            &self.board,
+            (self.view_pos(), self.view_size()),
+            self.config.density,
+            &self.theme,
+            self.selectable,
+            hint,
+            self.probs.as_deref(),
+        );
+        let border = Border::new(grid, false, &self.theme)
             .top_bar(self.get_stats())
-            .bot_bar(help);
+            .bot_bar(help)
+            .overflow(self.view_overflow());
+        self.center_board(border)
+    }
 
+    /// Centers the visible part of the board with the border on the screen
+    pub fn center_board(&self, border: Border<Message>) -> Element {
+        let view = self.view_size();
+        let (width, height) = self.config.density.cell_size();
         let mut layout = Layout::vertical().center();
-        layout.push(border, self.board.size.y * 3 + 6);
+        layout.push(border, view.y * height + BORDER_HEIGHT);
 
         let mut main = Layout::horizontal().center();
-        main.push(layout, self.board.size.x * 6 + 7);
+        main.push(layout, (view.x * width).max(MIN_WIDTH) + BORDER_WIDTH);
 
         main.into()
     }
 
     pub fn listen_game(&mut self, event: KeyEvent) -> Action {
-        match event.code {
-            KeyCode::Up | KeyCode::Char('k') => self.board.cur_up(),
-            KeyCode::Down | KeyCode::Char('j') => self.board.cur_down(),
-            KeyCode::Left | KeyCode::Char('h') => self.board.cur_left(),
-            KeyCode::Right | KeyCode::Char('l') => self.board.cur_right(),
-            KeyCode::Enter | KeyCode::Char('d') if self.state.is_playing() => {
-                self.reveal_board_cell();
-            }
-            KeyCode::Char('f') if self.state.is_playing() => {
-                self.flag_board_cell();
-            }
-            KeyCode::Char('r') => {
-                self.board.reset();
-                self.state = GameState::Playing;
-            }
-            KeyCode::Char('c') => self.board.center(),
-            KeyCode::Char('i') => self.screen = Screen::Help,
-            KeyCode::Tab => self.screen = Screen::DiffPicker,
-            KeyCode::Char('q') | KeyCode::Esc => return Action::QUIT,
-            _ => return Action::NONE,
+        let cur = self.board.cur;
+        let Some(action) = self.config.keys.get(&event, &GAME_ACTIONS) else {
+            return Action::NONE;
+        };
+        match action {
+            KeyAction::Up => self.board.cur_up(),
+            KeyAction::Down => self.board.cur_down(),
+            KeyAction::Left => self.board.cur_left(),
+            KeyAction::Right => self.board.cur_right(),
+            KeyAction::PanUp => self.pan(0, -1),
+            KeyAction::PanDown => self.pan(0, 1),
+            KeyAction::PanLeft => self.pan(-1, 0),
+            KeyAction::PanRight => self.pan(1, 0),
+            KeyAction::Reveal => self.reveal_cell(self.board.cur),
+            KeyAction::Chord => self.chord_cell(self.board.cur),
+            KeyAction::Flag => self.flag_cell(self.board.cur),
+            KeyAction::Undo => self.undo(),
+            KeyAction::Redo => self.redo(),
+            KeyAction::Restart => self.restart(false),
+            KeyAction::Replay => self.restart(true),
+            KeyAction::Hint => self.show_hint(),
+            KeyAction::Probs => self.toggle_probs(),
+            KeyAction::Center => self.board.center(),
+            KeyAction::Density => self.toggle_density(),
+            KeyAction::Export => self.export_board(),
+            KeyAction::Help => self.screen = Screen::Help,
+            KeyAction::Stats => self.screen = Screen::Stats,
+            KeyAction::Leaderboard => self.screen = Screen::Leaderboard,
+            KeyAction::Summary => self.show_summary(),
+            KeyAction::Picker => self.screen = Screen::DiffPicker,
+            KeyAction::Quit => return Action::QUIT,
+            KeyAction::Confirm => return Action::NONE,
+        }
+        if self.board.cur != cur {
+            self.recorder.push(ReplayAction::Select(self.board.cur));
+            self.scroll_to(self.board.cur);
         }
         Action::RENDER
     }
 
     pub fn message_game(&mut self, message: Message) -> Action {
         match message {
+            // Pressing left and right button together chords the number
+            Message::CellReveal(pos)
+                if self.held.contains(&MouseButton::Right)
+                    && self.board[pos].is_visible() =>
+            {
+                self.board.select(pos);
+                self.chord_cell(pos);
+            }
+            Message::CellFlag(pos)
+                if self.held.contains(&MouseButton::Left)
+                    && self.board[pos].is_visible() =>
+            {
+                self.board.select(pos);
+                self.chord_cell(pos);
+            }
             Message::CellReveal(pos) => {
                 self.board.select(pos);
-                if self.state.is_playing() {
-                    self.reveal_board_cell();
-                }
+                self.reveal_cell(pos);
             }
             Message::CellFlag(pos) => {
                 self.board.select(pos);
-                if self.state.is_playing() {
-                    self.flag_board_cell();
-                }
+                self.flag_cell(pos);
+            }
+            Message::CellChord(pos) => {
+                self.board.select(pos);
+                self.chord_cell(pos);
             }
             _ => return Action::NONE,
         }
@@ -80,34 +153,255 @@ impl App {
     fn get_stats(&self) -> Layout<Message> {
         let mut layout = Layout::horizontal();
         layout.push(
-            format!("{}", self.board.flags_left()).fg(Color::Hex(0x303030)),
+            format!("{}", self.board.flags_left()).fg(self.theme.bar),
+            Constraint::Min(0),
+        );
+        layout.push(Spacer::new(), Constraint::Fill(1));
+        layout.push(
+            format!("#{}", self.board.seed()).fg(self.theme.bar),
             Constraint::Min(0),
         );
         layout.push(Spacer::new(), Constraint::Fill(1));
 
-        if self.state == GameState::Win {
+        if self.board.state() == GameState::Win {
             layout.push(
-                "Victory!".fg(Color::Hex(0x303030)).bg(Color::Hex(0xbcbcbc)),
+                "Victory!".fg(self.theme.bar).bg(self.theme.face),
                 Constraint::Min(0),
             );
+            layout.push(Spacer::new(), 1);
         }
+        layout.push(
+            format_time(self.board.time()).fg(self.theme.bar),
+            Constraint::Min(0),
+        );
         layout
     }
 
-    fn reveal_board_cell(&mut self) {
-        if !self.board.reveal() {
-            self.state = GameState::GameOver;
-            self.board.reveal_mines();
+    /// Reveals the cell on the given position. Revealing visible number
+    /// chords it, unless chording is explicit only.
+    fn reveal_cell(&mut self, pos: Vec2) {
+        if self.board[pos].is_visible() {
+            if !self.config.explicit_chord {
+                self.chord_cell(pos);
+            }
+            return;
+        }
+
+        self.clear_msg();
+        let playing = self.board.state().is_playing();
+        self.recorder.push(ReplayAction::Reveal(pos));
+        self.board.reveal(pos);
+        if playing && !self.board.state().is_playing() {
+            self.record_game();
+        }
+        self.update_probs();
+    }
+
+    /// Reveals the neighbors of the number on the given position, when the
+    /// number of flags around it matches
+    fn chord_cell(&mut self, pos: Vec2) {
+        self.clear_msg();
+        let playing = self.board.state().is_playing();
+        self.recorder.push(ReplayAction::Chord(pos));
+        self.board.chord(pos);
+        if playing && !self.board.state().is_playing() {
+            self.record_game();
+        }
+        self.update_probs();
+    }
+
+    /// Toggles flag on the cell on the given position
+    fn flag_cell(&mut self, pos: Vec2) {
+        self.clear_msg();
+        let playing = self.board.state().is_playing();
+        self.recorder.push(ReplayAction::Flag(pos));
+        self.board.flag(pos);
+        if playing && !self.board.state().is_playing() {
+            self.record_game();
+        }
+        self.update_probs();
+    }
+
+    /// Writes the replay of the finished game, records it to the statistics
+    /// and shows its summary. Asks for the player name, when the won game
+    /// gets to the leaderboard. Assisted and imported games aren't recorded.
+    fn record_game(&mut self) {
+        self.write_replay();
+        self.screen = Screen::Summary;
+        if self.board.is_assisted() || self.board.is_imported() {
+            return;
+        }
+
+        let diff = self.board_diff();
+        let win = self.board.state() == GameState::Win;
+        let time = self.board.time();
+        self.recorded = Some((self.stats.clone(), self.leaderboard.clone()));
+        self.stats.record(diff.clone(), win, time);
+        if let Err(e) = self.stats.to_default_json() {
+            self.msg = Some(format!("Failed to save statistics: {e}"));
+        }
+        if win && self.leaderboard.qualifies(&diff, time) {
+            let bbbv = self.board.bbbv();
+            let seed = self.board.seed();
+            self.score = Some(Score::new(String::new(), time, seed, bbbv));
+            self.screen = Screen::NameInput;
+        }
+    }
+
+    /// Undoes the last move
+    fn undo(&mut self) {
+        self.clear_msg();
+        let ended = !self.board.state().is_playing();
+        if !self.board.undo() {
+            self.msg = Some("Nothing to undo".into());
+            return;
+        }
+
+        self.recorder.push(ReplayAction::Undo);
+        if ended {
+            self.msg =
+                Some("Game is assisted and won't count in stats".into());
+            self.unrecord_game();
+        }
+        self.update_probs();
+    }
+
+    /// Redoes the last undone move
+    fn redo(&mut self) {
+        self.clear_msg();
+        let playing = self.board.state().is_playing();
+        if !self.board.redo() {
+            self.msg = Some("Nothing to redo".into());
+            return;
+        }
+
+        self.recorder.push(ReplayAction::Redo);
+        if playing && !self.board.state().is_playing() {
+            self.record_game();
+        }
+        self.update_probs();
+    }
+
+    /// Restarts the game, keeps the same seed when `replay` is true
+    fn restart(&mut self, replay: bool) {
+        self.clear_msg();
+        self.end_game();
+        if replay {
+            self.board.replay();
+        } else {
+            self.board.reset();
+        }
+        self.recorder = Recorder::new(&self.board);
+        self.update_probs();
+    }
+
+    /// Removes the finished game, which became assisted, from the
+    /// statistics and the leaderboard, see [`App::record_game`]
+    fn unrecord_game(&mut self) {
+        let Some((stats, leaderboard)) = self.recorded.take() else {
+            return;
+        };
+        self.stats = stats;
+        self.leaderboard = leaderboard;
+        self.score = None;
+        if let Err(e) = self
+            .stats
+            .to_default_json()
+            .and_then(|_| self.leaderboard.to_default_json())
+        {
+            self.msg = Some(format!("Failed to save statistics: {e}"));
+        }
+    }
+
+    /// Ends the current game before it's replaced. Writes the replay of the
+    /// game in progress, which is abandoned.
+    pub fn end_game(&mut self) {
+        self.recorded = None;
+        if self.board.state().is_playing() && self.board.is_generated() {
+            self.write_replay();
         }
-        if self.board.win() {
-            self.state = GameState::Win;
+    }
+
+    /// Writes the replay of the current game
+    fn write_replay(&mut self) {
+        if let Err(e) = self.recorder.write() {
+            self.msg = Some(format!("Failed to save replay: {e}"));
         }
     }
 
-    fn flag_board_cell(&mut self) {
-        self.board.flag();
-        if self.board.win() {
-            self.state = GameState::Win;
+    /// Finds a cell, which can be deduced, and shows why in the bottom bar
+    fn show_hint(&mut self) {
+        self.clear_msg();
+        if !self.board.state().is_playing() {
+            return;
+        }
+        if !self.board.is_generated() {
+            self.msg = Some("Reveal any cell to start".into());
+            return;
         }
+
+        self.hint = Solver::new(&self.board).hint();
+        match &self.hint {
+            Some(hint) => self.scroll_to(hint.pos),
+            None => {
+                self.msg = Some("No certain move, you have to guess".into())
+            }
+        }
+    }
+
+    /// Switches to the next density of the board and remembers it in the
+    /// config
+    fn toggle_density(&mut self) {
+        self.config.density = self.config.density.next();
+        self.scroll_to(self.board.cur);
+        self.msg = Some(format!("Density: {}", self.config.density));
+        if let Err(e) = self.config.to_default_json() {
+            self.msg = Some(format!("Failed to save config: {e}"));
+        }
+    }
+
+    /// Shows the summary of the finished game
+    fn show_summary(&mut self) {
+        self.clear_msg();
+        if self.board.state().is_playing() {
+            self.msg = Some("Finish the game to see its summary".into());
+        } else {
+            self.screen = Screen::Summary;
+        }
+    }
+
+    /// Exports the board to a file in the text format and shows the path
+    /// in the bottom bar
+    fn export_board(&mut self) {
+        self.clear_msg();
+        if !self.board.is_generated() {
+            self.msg = Some("Reveal any cell to start".into());
+            return;
+        }
+        self.msg = Some(match export_board(&self.board) {
+            Ok(file) => format!("Board exported to {}", file.display()),
+            Err(e) => format!("Failed to export board: {e}"),
+        });
+    }
+
+    /// Toggles the mine probability overlay
+    fn toggle_probs(&mut self) {
+        self.probs = match self.probs {
+            Some(_) => None,
+            None => Some(Solver::new(&self.board).probabilities()),
+        };
+    }
+
+    /// Recalculates the mine probabilities, when the overlay is shown
+    pub fn update_probs(&mut self) {
+        if self.probs.is_some() {
+            self.probs = Some(Solver::new(&self.board).probabilities());
+        }
+    }
+
+    /// Clears the hint and the message in the bottom bar
+    fn clear_msg(&mut self) {
+        self.hint = None;
+        self.msg = None;
     }
 }
@@ -1,48 +1,104 @@
 use termint::{
-    enums::Color,
     geometry::Constraint,
-    prelude::{KeyCode, KeyEvent},
+    prelude::KeyEvent,
     term::Action,
     widgets::{Layout, Span, ToSpan},
 };
 
 use crate::{
     app::App,
-    game_state::Screen,
+    keys::KeyAction,
     message::Message,
+    screen::Screen,
     tui::{widgets::border::Border, Element},
 };
 
+/// Actions listed in the help page with their descriptions, keys of grouped
+/// actions are shown together
+const HELP_ITEMS: [(&[KeyAction], &str); 20] = [
+    (
+        &[
+            KeyAction::Left,
+            KeyAction::Down,
+            KeyAction::Up,
+            KeyAction::Right,
+        ],
+        "cursor movement",
+    ),
+    (
+        &[
+            KeyAction::PanLeft,
+            KeyAction::PanDown,
+            KeyAction::PanUp,
+            KeyAction::PanRight,
+        ],
+        "scroll the board",
+    ),
+    (&[KeyAction::Flag], "toggle flag"),
+    (&[KeyAction::Reveal], "display/reveal cell"),
+    (&[KeyAction::Chord], "chord the number"),
+    (&[KeyAction::Restart], "restart game"),
+    (&[KeyAction::Replay], "replay the same board"),
+    (&[KeyAction::Undo], "undo move"),
+    (&[KeyAction::Redo], "redo move"),
+    (&[KeyAction::Hint], "show hint"),
+    (&[KeyAction::Probs], "mine probabilities"),
+    (&[KeyAction::Help], "toggle help"),
+    (&[KeyAction::Stats], "toggle statistics"),
+    (&[KeyAction::Leaderboard], "toggle leaderboard"),
+    (&[KeyAction::Summary], "toggle game summary"),
+    (&[KeyAction::Center], "center the cursor"),
+    (&[KeyAction::Density], "change density"),
+    (&[KeyAction::Export], "export the board"),
+    (&[KeyAction::Picker], "open difficulty picker"),
+    (&[KeyAction::Quit], "quit game"),
+];
+
 impl App {
     /// Renders help page
     pub fn render_help(&self) -> Element {
+        let items: Vec<_> = HELP_ITEMS
+            .iter()
+            .map(|(actions, desc)| {
+                let ascii = self.theme.symbols.ascii;
+                (self.config.keys.label(actions, ascii), *desc)
+            })
+            .filter(|(key, _)| !key.is_empty())
+            .collect();
+        let key_len = items
+            .iter()
+            .map(|(key, _)| key.chars().count() + 2)
+            .max()
+            .unwrap_or_default();
+        let desc_len = items
+            .iter()
+            .map(|(_, desc)| desc.len())
+            .max()
+            .unwrap_or_default();
+
         let mut help = Layout::vertical().padding((1, 1, 1, 2));
-        help.push(Self::help_item("←↑↓→/hjkl", 11, "cursor movement"), 1);
-        help.push(Self::help_item("f", 11, "toggle flag"), 1);
-        help.push(Self::help_item("d/Enter", 11, "display/reveal cell"), 1);
-        help.push(Self::help_item("r", 11, "restart game"), 1);
-        help.push(Self::help_item("i", 11, "toggle help"), 1);
-        help.push(Self::help_item("c", 11, "center the cursor"), 1);
-        help.push(Self::help_item("Tab", 11, "open difficulty picker"), 1);
-        help.push(Self::help_item("Esc", 11, "quit game"), 1);
+        for (key, desc) in items.iter() {
+            help.push(self.help_item(key, key_len, desc), 1);
+        }
 
         let mut top_bar = Layout::horizontal();
-        top_bar.push("Help".fg(Color::Hex(0x303030)), Constraint::Min(0));
+        top_bar.push("Help".fg(self.theme.bar), Constraint::Min(0));
 
-        let border = Border::new(help, true).top_bar(top_bar);
+        let border = Border::new(help, true, &self.theme).top_bar(top_bar);
         let mut wrapper = Layout::vertical().center();
         wrapper.push(border, Constraint::Min(0));
 
         let mut layout = Layout::horizontal().center();
-        layout.push(wrapper, Constraint::Length(43));
+        layout.push(wrapper, Constraint::Length(key_len + desc_len + 10));
         layout.into()
     }
 
     /// Key listener for help page
     pub fn listen_help(&mut self, event: KeyEvent) -> Action {
-        match event.code {
-            KeyCode::Char('i') => self.screen = Screen::Game,
-            KeyCode::Esc | KeyCode::Char('q') => return Action::QUIT,
+        let actions = [KeyAction::Help, KeyAction::Quit];
+        match self.config.keys.get(&event, &actions) {
+            Some(KeyAction::Help) => self.screen = Screen::Game,
+            Some(KeyAction::Quit) => return Action::QUIT,
             _ => return Action::NONE,
         }
         Action::RENDER
@@ -51,18 +107,21 @@ impl App {
 
 impl App {
     /// Gets help item layout
-    fn help_item(key: &str, key_len: usize, action: &str) -> Layout<Message> {
+    fn help_item(
+        &self,
+        key: &str,
+        key_len: usize,
+        action: &str,
+    ) -> Layout<Message> {
         let mut layout = Layout::horizontal();
         layout.push(
             Span::new(format!("{key}:"))
-                .fg(Color::Hex(0x0000ff))
-                .bg(Color::Hex(0xbcbcbc)),
+                .fg(self.theme.accent)
+                .bg(self.theme.face),
             Constraint::Length(key_len),
         );
         layout.push(
-            Span::new(action)
-                .fg(Color::Hex(0x404040))
-                .bg(Color::Hex(0xbcbcbc)),
+            Span::new(action).fg(self.theme.text).bg(self.theme.face),
             Constraint::Fill(1),
         );
         layout
@@ -0,0 +1,72 @@
+use termint::{
+    geometry::Constraint,
+    prelude::KeyEvent,
+    term::Action,
+    widgets::{Layout, ToSpan},
+};
+
+use crate::{
+    app::App,
+    keys::KeyAction,
+    leaderboard::{SCORES_HEADER, SCORES_WIDTHS},
+    screen::Screen,
+    tui::{widgets::border::Border, Element},
+};
+
+impl App {
+    /// Renders leaderboard of the current difficulty
+    pub fn render_leaderboard(&self) -> Element {
+        let diff = self.board_diff();
+        let mut scores = Layout::vertical().padding((1, 1, 1, 2));
+        scores.push(
+            self.table_row(&SCORES_HEADER, &SCORES_WIDTHS, self.theme.accent),
+            1,
+        );
+        for (rank, score) in self.leaderboard.get(&diff).iter().enumerate() {
+            let fg = if self.rank == Some(rank) {
+                self.theme.accent
+            } else {
+                self.theme.text
+            };
+            scores.push(
+                self.table_row(&score.columns(rank), &SCORES_WIDTHS, fg),
+                1,
+            );
+        }
+        if self.leaderboard.get(&diff).is_empty() {
+            scores.push(
+                "No games won yet".fg(self.theme.text).bg(self.theme.face),
+                1,
+            );
+        }
+
+        let mut top_bar = Layout::horizontal();
+        top_bar.push(
+            format!("Leaderboard - {diff}").fg(self.theme.bar),
+            Constraint::Min(0),
+        );
+
+        let border = Border::new(scores, true, &self.theme).top_bar(top_bar);
+        let mut wrapper = Layout::vertical().center();
+        wrapper.push(border, Constraint::Min(0));
+
+        let width = SCORES_WIDTHS.iter().sum::<usize>() + 10;
+        let mut layout = Layout::horizontal().center();
+        layout.push(wrapper, Constraint::Length(width));
+        layout.into()
+    }
+
+    /// Key listener for leaderboard page
+    pub fn listen_leaderboard(&mut self, event: KeyEvent) -> Action {
+        let actions = [KeyAction::Leaderboard, KeyAction::Quit];
+        match self.config.keys.get(&event, &actions) {
+            Some(KeyAction::Leaderboard) => {
+                self.rank = None;
+                self.screen = Screen::Game;
+            }
+            Some(KeyAction::Quit) => return Action::QUIT,
+            _ => return Action::NONE,
+        }
+        Action::RENDER
+    }
+}
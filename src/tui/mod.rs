@@ -1,6 +1,18 @@
+pub mod board;
+pub mod cell;
+pub mod color_mode;
+pub mod custom;
+pub mod density;
 pub mod diff_picker;
 pub mod game;
 pub mod help;
+pub mod leaderboard;
+pub mod name_input;
+pub mod replay;
+pub mod stats;
+pub mod summary;
+pub mod symbols;
+pub mod viewport;
 pub mod widgets;
 
 // pub use widgets::raw_span;
@@ -0,0 +1,90 @@
+use termint::{
+    geometry::Constraint,
+    prelude::{KeyCode, KeyEvent},
+    term::Action,
+    widgets::{Layout, ToSpan},
+};
+
+use crate::{
+    app::App,
+    screen::Screen,
+    stats::format_time,
+    tui::{widgets::border::Border, Element},
+};
+
+/// Maximum length of the player name
+const MAX_NAME_LEN: usize = 16;
+
+impl App {
+    /// Renders input of the player name for the leaderboard
+    pub fn render_name_input(&self) -> Element {
+        let mut input = Layout::vertical().padding((1, 1, 1, 2));
+        if let Some(score) = &self.score {
+            input.push(
+                format!(
+                    "Time: {}, 3BV/s: {:.2}",
+                    format_time(score.time),
+                    score.bbbv_rate()
+                )
+                .fg(self.theme.text)
+                .bg(self.theme.face),
+                1,
+            );
+        }
+        input.push(
+            format!("Name: {}_", self.name)
+                .fg(self.theme.accent)
+                .bg(self.theme.face),
+            1,
+        );
+
+        let mut top_bar = Layout::horizontal();
+        top_bar.push("New best time!".fg(self.theme.bar), Constraint::Min(0));
+        let bot_bar = "Enter to save, Esc to skip".fg(self.theme.bar);
+
+        let border = Border::new(input, true, &self.theme)
+            .top_bar(top_bar)
+            .bot_bar(bot_bar);
+        let mut wrapper = Layout::vertical().center();
+        wrapper.push(border, Constraint::Min(0));
+
+        let mut layout = Layout::horizontal().center();
+        layout.push(wrapper, Constraint::Length(40));
+        layout.into()
+    }
+
+    /// Key listener for the player name input
+    pub fn listen_name_input(&mut self, event: KeyEvent) -> Action {
+        match event.code {
+            KeyCode::Char(c) if self.name.chars().count() < MAX_NAME_LEN => {
+                self.name.push(c)
+            }
+            KeyCode::Backspace => _ = self.name.pop(),
+            KeyCode::Enter => self.add_score(),
+            KeyCode::Esc => {
+                self.score = None;
+                self.screen = Screen::Summary;
+            }
+            _ => return Action::NONE,
+        }
+        Action::RENDER
+    }
+
+    /// Adds the won game to the leaderboard with the entered name and shows
+    /// the leaderboard
+    fn add_score(&mut self) {
+        let Some(mut score) = self.score.take() else {
+            return;
+        };
+        score.name = match self.name.trim() {
+            "" => "Anonymous".into(),
+            name => name.into(),
+        };
+
+        self.rank = self.leaderboard.add(self.board_diff(), score);
+        if let Err(e) = self.leaderboard.to_default_json() {
+            self.msg = Some(format!("Failed to save leaderboard: {e}"));
+        }
+        self.screen = Screen::Leaderboard;
+    }
+}
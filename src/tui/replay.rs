@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use termint::{
+    geometry::Constraint,
+    prelude::{KeyCode, KeyEvent},
+    term::Action,
+    widgets::{Layout, Spacer, ToSpan},
+};
+
+use crate::{app::App, stats::format_time, tui::Element};
+
+use super::{board::board_element, widgets::border::Border};
+
+impl App {
+    /// Renders the replay playback
+    pub fn render_replay(&self) -> Element {
+        let Some(player) = &self.player else {
+            return Spacer::new().into();
+        };
+
+        let mut top_bar = Layout::horizontal();
+        top_bar.push(
+            format!("{}", self.board.flags_left()).fg(self.theme.bar),
+            Constraint::Min(0),
+        );
+        top_bar.push(Spacer::new(), Constraint::Fill(1));
+        top_bar.push(
+            format!("#{}", self.board.seed()).fg(self.theme.bar),
+            Constraint::Min(0),
+        );
+        top_bar.push(Spacer::new(), Constraint::Fill(1));
+        top_bar.push(
+            format!(
+                "{}/{} x{}",
+                format_time(player.time),
+                format_time(player.replay.duration()),
+                player.speed
+            )
+            .fg(self.theme.bar),
+            Constraint::Min(0),
+        );
+
+        let symbols = &self.theme.symbols;
+        let state = if player.playing {
+            symbols.play
+        } else {
+            symbols.pause
+        };
+        let step = if symbols.ascii { "hl" } else { "←→" };
+        let bot_bar = format!("{state} Space play, {step} step, +- speed")
+            .fg(self.theme.bar);
+
+        let view = (self.view_pos(), self.view_size());
+        let density = self.config.density;
+        let grid = board_element(
+            &self.board,
+            view,
+            density,
+            &self.theme,
+            true,
+            None,
+            None,
+        );
+        let border = Border::new(grid, false, &self.theme)
+            .top_bar(top_bar)
+            .bot_bar(bot_bar)
+            .overflow(self.view_overflow());
+        self.center_board(border)
+    }
+
+    /// Key listener for the replay playback
+    pub fn listen_replay(&mut self, event: KeyEvent) -> Action {
+        let Some(player) = &mut self.player else {
+            return Action::NONE;
+        };
+
+        match event.code {
+            KeyCode::Char(' ') => player.toggle(&mut self.board),
+            KeyCode::Right | KeyCode::Char('l') => {
+                player.step(&mut self.board)
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                player.step_back(&mut self.board)
+            }
+            KeyCode::Char('+') => player.faster(),
+            KeyCode::Char('-') => player.slower(),
+            KeyCode::Esc | KeyCode::Char('q') => return Action::QUIT,
+            _ => return Action::NONE,
+        }
+        self.scroll_to(self.board.cur);
+        Action::RENDER
+    }
+
+    /// Advances the replay playback
+    pub fn update_replay(&mut self, delta: Duration) -> Action {
+        let Some(player) = &mut self.player else {
+            return Action::NONE;
+        };
+
+        let secs = player.time.as_secs();
+        let changed = player.advance(&mut self.board, delta);
+        let tick = player.time.as_secs() != secs;
+        if changed {
+            self.scroll_to(self.board.cur);
+        }
+        if changed || tick {
+            Action::RENDER
+        } else {
+            Action::NONE
+        }
+    }
+}
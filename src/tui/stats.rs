@@ -0,0 +1,87 @@
+use termint::{
+    enums::Color,
+    geometry::Constraint,
+    prelude::KeyEvent,
+    term::Action,
+    widgets::{Layout, Span, ToSpan},
+};
+
+use crate::{
+    app::App,
+    keys::KeyAction,
+    message::Message,
+    screen::Screen,
+    stats::{STATS_HEADER, STATS_WIDTHS},
+    tui::{widgets::border::Border, Element},
+};
+
+impl App {
+    /// Renders statistics page
+    pub fn render_stats(&self) -> Element {
+        let mut stats = Layout::vertical().padding((1, 1, 1, 2));
+        stats.push(
+            self.table_row(&STATS_HEADER, &STATS_WIDTHS, self.theme.accent),
+            1,
+        );
+        for diff in &self.stats.diffs {
+            stats.push(
+                self.table_row(
+                    &diff.columns(),
+                    &STATS_WIDTHS,
+                    self.theme.text,
+                ),
+                1,
+            );
+        }
+        if self.stats.diffs.is_empty() {
+            stats.push(
+                "No finished games yet"
+                    .fg(self.theme.text)
+                    .bg(self.theme.face),
+                1,
+            );
+        }
+
+        let mut top_bar = Layout::horizontal();
+        top_bar.push("Statistics".fg(self.theme.bar), Constraint::Min(0));
+
+        let border = Border::new(stats, true, &self.theme).top_bar(top_bar);
+        let mut wrapper = Layout::vertical().center();
+        wrapper.push(border, Constraint::Min(0));
+
+        let width = STATS_WIDTHS.iter().sum::<usize>() + 10;
+        let mut layout = Layout::horizontal().center();
+        layout.push(wrapper, Constraint::Length(width));
+        layout.into()
+    }
+
+    /// Key listener for statistics page
+    pub fn listen_stats(&mut self, event: KeyEvent) -> Action {
+        let actions = [KeyAction::Stats, KeyAction::Quit];
+        match self.config.keys.get(&event, &actions) {
+            Some(KeyAction::Stats) => self.screen = Screen::Game,
+            Some(KeyAction::Quit) => return Action::QUIT,
+            _ => return Action::NONE,
+        }
+        Action::RENDER
+    }
+}
+
+impl App {
+    /// Gets row of a table with columns of given widths
+    pub fn table_row<T: AsRef<str>>(
+        &self,
+        cols: &[T],
+        widths: &[usize],
+        fg: Color,
+    ) -> Layout<Message> {
+        let mut layout = Layout::horizontal();
+        for (col, width) in cols.iter().zip(widths.iter().copied()) {
+            layout.push(
+                Span::new(col.as_ref()).fg(fg).bg(self.theme.face),
+                Constraint::Length(width),
+            );
+        }
+        layout
+    }
+}
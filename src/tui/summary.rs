@@ -0,0 +1,133 @@
+use minesweeper::{game_state::GameState, replay::ReplayAction};
+use termint::{
+    geometry::Constraint,
+    prelude::KeyEvent,
+    term::Action,
+    widgets::{Layout, Span, ToSpan},
+};
+
+use crate::{
+    app::App,
+    keys::KeyAction,
+    message::Message,
+    screen::Screen,
+    stats::format_time,
+    tui::{widgets::border::Border, Element},
+};
+
+/// Width of the labels of the summary items
+const LABEL_WIDTH: usize = 12;
+
+impl App {
+    /// Renders summary of the finished game
+    pub fn render_summary(&self) -> Element {
+        let board = &self.board;
+        let win = board.state() == GameState::Win;
+        let secs = board.time().as_secs_f64();
+        let bbbv = board.bbbv();
+        let solved = board.bbbv_solved();
+        let (left, right, chord) = self.clicks();
+        let clicks = left + right + chord;
+
+        let mut items = vec![
+            ("Time", format_time(board.time())),
+            match win {
+                true => ("3BV", bbbv.to_string()),
+                false => ("3BV", format!("{solved}/{bbbv}")),
+            },
+            ("3BV/s", format!("{:.2}", rate(solved, secs))),
+            (
+                "Clicks",
+                format!(
+                    "{clicks} ({left} left, {right} right, {chord} chord)"
+                ),
+            ),
+            (
+                "Efficiency",
+                format!("{:.0}%", rate(solved, clicks as f64) * 100.),
+            ),
+            ("Openings", board.openings().to_string()),
+            ("Islands", board.islands().to_string()),
+        ];
+        if !win {
+            items.push(("Cleared", format!("{:.1}%", board.cleared() * 100.)));
+        }
+
+        let mut summary = Layout::vertical().padding((1, 1, 1, 2));
+        for (label, value) in items.iter() {
+            summary.push(self.summary_item(label, value), 1);
+        }
+        let width = items
+            .iter()
+            .map(|(_, value)| value.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let title = if win { "Victory!" } else { "Game over" };
+        let mut top_bar = Layout::horizontal();
+        top_bar.push(title.fg(self.theme.bar), Constraint::Min(0));
+        let ascii = self.theme.symbols.ascii;
+        let key = self.config.keys.label(&[KeyAction::Summary], ascii);
+        let bot_bar = format!("Press {key} to close").fg(self.theme.bar);
+
+        let border = Border::new(summary, true, &self.theme)
+            .top_bar(top_bar)
+            .bot_bar(bot_bar);
+        let mut wrapper = Layout::vertical().center();
+        wrapper.push(border, Constraint::Min(0));
+
+        let mut layout = Layout::horizontal().center();
+        layout.push(wrapper, Constraint::Length(LABEL_WIDTH + width + 10));
+        layout.into()
+    }
+
+    /// Key listener for the game summary
+    pub fn listen_summary(&mut self, event: KeyEvent) -> Action {
+        let actions = [KeyAction::Summary, KeyAction::Quit];
+        match self.config.keys.get(&event, &actions) {
+            Some(KeyAction::Summary) => self.screen = Screen::Game,
+            Some(KeyAction::Quit) => return Action::QUIT,
+            _ => return Action::NONE,
+        }
+        Action::RENDER
+    }
+}
+
+impl App {
+    /// Gets summary item layout
+    fn summary_item(&self, label: &str, value: &str) -> Layout<Message> {
+        let mut layout = Layout::horizontal();
+        layout.push(
+            Span::new(format!("{label}:"))
+                .fg(self.theme.accent)
+                .bg(self.theme.face),
+            Constraint::Length(LABEL_WIDTH),
+        );
+        layout.push(
+            Span::new(value).fg(self.theme.text).bg(self.theme.face),
+            Constraint::Fill(1),
+        );
+        layout
+    }
+
+    /// Counts the reveals, flags and chords of the current game
+    fn clicks(&self) -> (usize, usize, usize) {
+        let events = &self.recorder.replay.events;
+        let count = |f: fn(&ReplayAction) -> bool| {
+            events.iter().filter(|e| f(&e.action)).count()
+        };
+        (
+            count(|a| matches!(a, ReplayAction::Reveal(_))),
+            count(|a| matches!(a, ReplayAction::Flag(_))),
+            count(|a| matches!(a, ReplayAction::Chord(_))),
+        )
+    }
+}
+
+/// Gets the ratio of the values, 0 when dividing by 0
+fn rate(val: usize, per: f64) -> f64 {
+    if per == 0. {
+        return 0.;
+    }
+    val as f64 / per
+}
@@ -0,0 +1,85 @@
+use std::env;
+
+/// Characters the game is drawn with. ASCII symbols are used on terminals,
+/// which can't display the Unicode block characters and emojis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbols {
+    /// Whether the widgets are drawn with plain ASCII characters
+    pub ascii: bool,
+    pub flag: &'static str,
+    /// Mine padded to 2 columns
+    pub mine: &'static str,
+    /// Marker of the hinted cell
+    pub hint: &'static str,
+    /// Hidden cell in the denser densities, when it can't be shown by color
+    pub hidden: &'static str,
+    /// Scroll indicators pointing up, down, left and right
+    pub arrows: [&'static str; 4],
+    pub play: &'static str,
+    pub pause: &'static str,
+    /// Icon in front of the help message in the bottom bar
+    pub info: &'static str,
+}
+
+impl Symbols {
+    pub const UNICODE: Self = Self {
+        ascii: false,
+        flag: "▶",
+        mine: "💣",
+        hint: "◆",
+        hidden: "·",
+        arrows: ["▲", "▼", "◀", "▶"],
+        play: "▶",
+        pause: "⏸",
+        info: "🛈 ",
+    };
+
+    pub const ASCII: Self = Self {
+        ascii: true,
+        flag: "F",
+        mine: "* ",
+        hint: "+",
+        hidden: ".",
+        arrows: ["^", "v", "<", ">"],
+        play: ">",
+        pause: "=",
+        info: "",
+    };
+
+    /// Gets the symbols based on the ASCII mode, it's detected from the
+    /// environment when [`None`]
+    pub fn new(ascii: Option<bool>) -> Self {
+        match ascii.unwrap_or_else(|| !unicode_supported()) {
+            true => Self::ASCII,
+            false => Self::UNICODE,
+        }
+    }
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Self::UNICODE
+    }
+}
+
+/// Checks whether the terminal can display Unicode based on the `TERM` and
+/// the locale environment variables
+fn unicode_supported() -> bool {
+    if let Ok("linux" | "vt100" | "vt220" | "dumb") =
+        env::var("TERM").as_deref()
+    {
+        return false;
+    }
+
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|val| !val.is_empty());
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => true,
+    }
+}
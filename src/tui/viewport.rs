@@ -0,0 +1,100 @@
+use minesweeper::vec2::Vec2;
+use termint::term::backend::{KeyModifiers, MouseEvent, MouseEventKind};
+
+use crate::app::App;
+
+use super::widgets::border::Overflow;
+
+/// Minimum number of columns of the board content, so the bars fit even
+/// when the board is narrow
+pub const MIN_WIDTH: usize = 44;
+/// Number of columns taken by the border around the board
+pub const BORDER_WIDTH: usize = 7;
+/// Number of lines taken by the border around the board with both bars
+pub const BORDER_HEIGHT: usize = 6;
+
+impl App {
+    /// Gets the number of cells of the board visible in the terminal
+    pub fn view_size(&self) -> Vec2 {
+        let size = self.board.size;
+        let (width, height) = self.config.density.cell_size();
+        let cols = self.term_size.x.saturating_sub(BORDER_WIDTH) / width;
+        let rows = self.term_size.y.saturating_sub(BORDER_HEIGHT) / height;
+        Vec2::new(cols.max(1).min(size.x), rows.max(1).min(size.y))
+    }
+
+    /// Gets the position of the top left visible cell of the board
+    pub fn view_pos(&self) -> Vec2 {
+        let (size, view) = (self.board.size, self.view_size());
+        Vec2::new(
+            self.scroll.x.min(size.x - view.x),
+            self.scroll.y.min(size.y - view.y),
+        )
+    }
+
+    /// Gets the directions in which the board continues beyond the view
+    pub fn view_overflow(&self) -> Overflow {
+        let (pos, view) = (self.view_pos(), self.view_size());
+        Overflow {
+            top: pos.y > 0,
+            bottom: pos.y + view.y < self.board.size.y,
+            left: pos.x > 0,
+            right: pos.x + view.x < self.board.size.x,
+        }
+    }
+
+    /// Scrolls the view by the least amount, so the cell on the given
+    /// position is visible
+    pub fn scroll_to(&mut self, pos: Vec2) {
+        let (start, view) = (self.view_pos(), self.view_size());
+        self.scroll = Vec2::new(
+            scroll_axis(start.x, view.x, pos.x),
+            scroll_axis(start.y, view.y, pos.y),
+        );
+    }
+
+    /// Centers the view on the cell on the given position
+    pub fn center_view(&mut self, pos: Vec2) {
+        let view = self.view_size();
+        self.scroll = Vec2::new(
+            pos.x.saturating_sub(view.x / 2),
+            pos.y.saturating_sub(view.y / 2),
+        );
+    }
+
+    /// Moves the view by the given number of cells
+    pub fn pan(&mut self, x: isize, y: isize) {
+        let start = self.view_pos();
+        self.scroll = Vec2::new(
+            start.x.saturating_add_signed(x),
+            start.y.saturating_add_signed(y),
+        );
+    }
+
+    /// Pans the view when scrolling the mouse wheel, horizontally when
+    /// holding shift. Returns false when the event isn't a scroll.
+    pub fn scroll_view(&mut self, event: &MouseEvent) -> bool {
+        let shift = event.modifiers.contains(KeyModifiers::SHIFT);
+        match event.kind {
+            MouseEventKind::ScrollUp if shift => self.pan(-1, 0),
+            MouseEventKind::ScrollDown if shift => self.pan(1, 0),
+            MouseEventKind::ScrollUp => self.pan(0, -1),
+            MouseEventKind::ScrollDown => self.pan(0, 1),
+            MouseEventKind::ScrollLeft => self.pan(-1, 0),
+            MouseEventKind::ScrollRight => self.pan(1, 0),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Gets the start of the view on one axis, so the given position is visible
+fn scroll_axis(start: usize, len: usize, pos: usize) -> usize {
+    if pos < start {
+        pos
+    } else if pos >= start + len {
+        pos + 1 - len
+    } else {
+        start
+    }
+}
@@ -6,15 +6,34 @@ use termint::{
     widgets::{Element, LayoutNode, Widget},
 };
 
+use crate::{theme::Theme, tui::symbols::Symbols};
+
 pub struct Border<M: 'static> {
     content: Element<M>,
     top_bar: Option<Element<M>>,
     bot_bar: Option<Element<M>>,
     bg: bool,
+    overflow: Overflow,
+    /// Face, light and shadow colors of the border
+    colors: (Color, Color, Color),
+    symbols: Symbols,
+    /// Whether the border is drawn without the 3D effect
+    flat: bool,
+}
+
+/// Directions in which the content continues beyond the border, shown by
+/// scroll indicators on the border
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Overflow {
+    pub top: bool,
+    pub bottom: bool,
+    pub left: bool,
+    pub right: bool,
 }
 
 impl<M: Clone + 'static> Border<M> {
-    pub fn new<E>(content: E, bg: bool) -> Self
+    /// Creates new [`Border`] around the content with colors of given theme
+    pub fn new<E>(content: E, bg: bool, theme: &Theme) -> Self
     where
         E: Into<Element<M>>,
     {
@@ -23,6 +42,10 @@ impl<M: Clone + 'static> Border<M> {
             top_bar: None,
             bot_bar: None,
             bg,
+            overflow: Overflow::default(),
+            colors: (theme.face, theme.light, theme.shadow),
+            symbols: theme.symbols,
+            flat: theme.flat(),
         }
     }
 
@@ -43,13 +66,24 @@ impl<M: Clone + 'static> Border<M> {
         self.bot_bar = Some(bar.into());
         self
     }
+
+    /// Sets directions in which scroll indicators are shown
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
 }
 
 impl<M: Clone + 'static> Widget<M> for Border<M> {
     fn render(&self, buffer: &mut Buffer, node: &LayoutNode) {
-        self.render_inner(buffer, node);
+        if self.flat {
+            self.render_flat(buffer, node);
+        } else {
+            self.render_inner(buffer, node);
+        }
 
         self.content.render(buffer, &node.children[0]);
+        self.render_overflow(buffer, &node.children[0].area);
     }
 
     fn height(&self, size: &Vec2) -> usize {
@@ -102,7 +136,7 @@ impl<M: Clone + 'static> Widget<M> for Border<M> {
 impl<M: Clone + 'static> Border<M> {
     fn render_inner(&self, buffer: &mut Buffer, node: &LayoutNode) {
         let rect = node.area;
-        let (bc, ff, sn) = Self::get_colors();
+        let (bc, ff, sn) = self.colors;
 
         let hframe_width = rect.width().saturating_sub(7);
         let hframe = "▄".repeat(hframe_width);
@@ -209,6 +243,77 @@ impl<M: Clone + 'static> Border<M> {
         buffer.set_str_styled(" ", &Vec2::new(end, pos.y), snbc);
     }
 
+    /// Renders the border with ASCII characters, the frames are at the same
+    /// positions as the frames of the regular border
+    fn render_flat(&self, buffer: &mut Buffer, node: &LayoutNode) {
+        let rect = node.area;
+        let (bc, _, sn) = self.colors;
+        let style = Style::new().bg(bc).fg(sn);
+
+        let hframe_width = rect.width().saturating_sub(7);
+        let outer = format!("+{}+ ", "-".repeat(hframe_width + 4));
+        let inner = format!("| +{}+ | ", "-".repeat(hframe_width));
+        let bar = format!("|{}| ", " ".repeat(hframe_width + 4));
+        let content = format!("| |{}| | ", " ".repeat(hframe_width));
+
+        let mut pos = *rect.pos();
+        let mut line = |text: &str, pos: &mut Vec2| {
+            buffer.set_str_styled(text, pos, style);
+            buffer.set_bg(sn, &Vec2::new(pos.x + hframe_width + 6, pos.y));
+            pos.y += 1;
+        };
+
+        line(&outer, &mut pos);
+        if self.top_bar.is_some() {
+            line(&bar, &mut pos);
+        }
+        line(&inner, &mut pos);
+        for _ in 0..node.children[0].area.height() {
+            line(&content, &mut pos);
+        }
+        line(&inner, &mut pos);
+        if self.bot_bar.is_some() {
+            line(&bar, &mut pos);
+        }
+        line(&outer, &mut pos);
+
+        let mut cid = 1;
+        if let Some(top) = &self.top_bar {
+            top.render(buffer, &node.children[cid]);
+            cid += 1;
+        }
+        if let Some(bot) = &self.bot_bar {
+            bot.render(buffer, &node.children[cid]);
+        }
+    }
+
+    /// Renders scroll indicators around the content in given area
+    fn render_overflow(&self, buffer: &mut Buffer, area: &Rect) {
+        let (bc, _, sn) = self.colors;
+        let style = Style::new().bg(bc).fg(sn);
+
+        let mid = Vec2::new(
+            area.x() + area.width() / 2,
+            area.y() + area.height() / 2,
+        );
+        if self.overflow.top {
+            let pos = Vec2::new(mid.x, area.y().saturating_sub(1));
+            buffer.set_str_styled(self.symbols.arrows[0], &pos, style);
+        }
+        if self.overflow.bottom {
+            let pos = Vec2::new(mid.x, area.y() + area.height());
+            buffer.set_str_styled(self.symbols.arrows[1], &pos, style);
+        }
+        if self.overflow.left {
+            let pos = Vec2::new(area.x().saturating_sub(1), mid.y);
+            buffer.set_str_styled(self.symbols.arrows[2], &pos, style);
+        }
+        if self.overflow.right {
+            let pos = Vec2::new(area.x() + area.width(), mid.y);
+            buffer.set_str_styled(self.symbols.arrows[3], &pos, style);
+        }
+    }
+
     fn content_padding(&self) -> Padding {
         let mut padding = Padding::new(2, 4, 2, 3);
         padding.top += self.top_bar.is_some() as usize;
@@ -219,14 +324,6 @@ impl<M: Clone + 'static> Border<M> {
     fn bar_height(&self) -> usize {
         4 + self.top_bar.is_some() as usize + self.bot_bar.is_some() as usize
     }
-
-    fn get_colors() -> (Color, Color, Color) {
-        (
-            Color::Hex(0xbcbcbc),
-            Color::Hex(0xffffff),
-            Color::Hex(0x797979),
-        )
-    }
 }
 
 impl<M: Clone + 'static> From<Border<M>> for Element<M> {
@@ -1,26 +1,40 @@
 use termint::{
     buffer::{Buffer, Cell},
-    enums::Color,
+    enums::{Color, Modifier},
     geometry::{Rect, Vec2},
     style::Style,
     widgets::{Element, LayoutNode, Widget},
 };
 
+use crate::theme::Theme;
+
 #[derive(Debug)]
 pub struct Button<M: 'static> {
     content: Element<M>,
     selected: bool,
+    /// Face, shadow and light colors of the button
+    colors: (Color, Color, Color),
+    /// Face, shadow and light colors of the selected button
+    sel_colors: (Color, Color, Color),
+    /// Whether the button is drawn without the 3D effect
+    flat: bool,
+    /// Whether the selected button is shown by reversed content
+    reverse: bool,
 }
 
 impl<M: Clone + 'static> Button<M> {
-    /// Creates new minesweeper style [`Button`]
-    pub fn new<E>(content: E) -> Self
+    /// Creates new minesweeper style [`Button`] with colors of given theme
+    pub fn new<E>(content: E, theme: &Theme) -> Self
     where
         E: Into<Element<M>>,
     {
         Self {
             content: content.into(),
             selected: false,
+            colors: theme.button(false),
+            sel_colors: theme.button(true),
+            flat: theme.flat(),
+            reverse: theme.colors.modifiers(),
         }
     }
 
@@ -39,6 +53,42 @@ impl<M: Clone + 'static> Button<M> {
 
 impl<M: Clone + 'static> Widget<M> for Button<M> {
     fn render(&self, buffer: &mut Buffer, node: &LayoutNode) {
+        if self.flat {
+            self.render_flat(buffer, node);
+        } else {
+            self.render_3d(buffer, node);
+        }
+
+        self.content.render(buffer, &node.children[0]);
+        if self.selected && self.reverse {
+            for pos in node.children[0].area {
+                if let Some(cell) = buffer.cell_mut(&pos) {
+                    cell.modifier.insert(Modifier::INVERSED);
+                }
+            }
+        }
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        3
+    }
+
+    fn width(&self, size: &Vec2) -> usize {
+        self.content.width(&Vec2::new(size.x, 1)) + 3
+    }
+
+    fn children(&self) -> Vec<&Element<M>> {
+        vec![&self.content]
+    }
+
+    fn layout(&self, node: &mut LayoutNode, area: Rect) {
+        node.children[0].layout(&self.content, area.inner((1, 1, 1, 2)));
+    }
+}
+
+impl<M: Clone + 'static> Button<M> {
+    /// Renders the 3D frame of the button
+    fn render_3d(&self, buffer: &mut Buffer, node: &LayoutNode) {
         let rect = node.area;
         let (lb, db, w) = self.get_colors();
 
@@ -76,41 +126,32 @@ impl<M: Clone + 'static> Widget<M> for Button<M> {
             Style::new().bg(lb).fg(db),
         );
         buffer.set_fg(w, &pos);
-
-        self.content.render(buffer, &node.children[0]);
-    }
-
-    fn height(&self, _size: &Vec2) -> usize {
-        3
-    }
-
-    fn width(&self, size: &Vec2) -> usize {
-        self.content.width(&Vec2::new(size.x, 1)) + 3
     }
 
-    fn children(&self) -> Vec<&Element<M>> {
-        vec![&self.content]
-    }
+    /// Renders the button as an ASCII box around the content
+    fn render_flat(&self, buffer: &mut Buffer, node: &LayoutNode) {
+        let (lb, db, _) = self.get_colors();
+        let style = Style::new().bg(lb).fg(db);
 
-    fn layout(&self, node: &mut LayoutNode, area: Rect) {
-        node.children[0].layout(&self.content, area.inner((1, 1, 1, 2)));
+        let width = node.children[0].area.width();
+        let hline = format!(" +{}+", "-".repeat(width));
+        let mut pos = *node.area.pos();
+        buffer.set_str_styled(&hline, &pos, style);
+        pos.y += 1;
+        buffer.set_str_styled(
+            format!(" |{}|", " ".repeat(width)),
+            &pos,
+            style,
+        );
+        pos.y += 1;
+        buffer.set_str_styled(hline, &pos, style);
     }
-}
 
-impl<M: Clone + 'static> Button<M> {
     fn get_colors(&self) -> (Color, Color, Color) {
         if self.selected {
-            (
-                Color::Hex(0x999999),
-                Color::Hex(0x696969),
-                Color::Hex(0xcccccc),
-            )
+            self.sel_colors
         } else {
-            (
-                Color::Hex(0xbcbcbc),
-                Color::Hex(0x797979),
-                Color::Hex(0xffffff),
-            )
+            self.colors
         }
     }
 }
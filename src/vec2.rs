@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Two dimensional vector, used for board sizes and cell positions
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize,
+)]
+pub struct Vec2 {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Vec2 {
+    /// Creates new [`Vec2`] with given coordinates
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}